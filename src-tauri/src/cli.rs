@@ -0,0 +1,692 @@
+//! Headless entry point used by the `video-transcript-cli` binary. It drives the same probe,
+//! chunking, cost and transcription code as the Tauri commands, without a window.
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::fs;
+use tokio_util::sync::CancellationToken;
+
+use crate::commands::processing::{
+    chat_translation_target, ensure_can_translate_audio, ensure_not_cancelled, plan_chunks,
+    resolve_output_path, transcribe_chunk, translate_segments, translated_output_path,
+    translates_audio_to_english, PipelineContext, ProgressReporter, SubtitleOutput,
+};
+use crate::commands::subtitles::{mux_subtitle_tracks, MuxContainer, MuxTrack};
+use crate::commands::video::{estimate_cost_for, probe_video, resolve_audio_stream, VideoInfo};
+use crate::models::job::new_job_id;
+use crate::models::processing::{ProcessingProgress, ProcessingRetry, ProcessingStage};
+use crate::models::queue::JobOverrides;
+use crate::models::settings::AppSettings;
+use crate::providers::{self, translation};
+use crate::utils::ffmpeg::Sidecars;
+use crate::utils::retry::RetryPolicy;
+use crate::utils::subtitle_file::write_subtitle_file;
+use crate::utils::{srt, temp};
+
+const API_KEY_ENV_VARS: &[&str] = &["VIDEO_TRANSCRIPT_API_KEY", "OPENAI_API_KEY"];
+const TEMP_DIR_NAME: &str = "video-transcript-cli";
+
+const USAGE: &str = "\
+Usage: video-transcript-cli <COMMAND> [OPTIONS] <VIDEO>...
+
+Commands:
+  probe         Print duration, codecs, tracks and chapters of each video
+  chunks        Print the chunks each video would be split into
+  cost          Print the estimated transcription cost of each video
+  transcribe    Transcribe each video and write its subtitle file
+
+Options:
+  --settings <FILE>       Read settings from a settings.json saved by the app
+  --provider <NAME>       openai, azure, compatible or whispercpp
+  --model <FILE>          whisper.cpp model file
+  --chunking <MODE>       fixed, silence or overlap
+  --chunk-minutes <N>     Target chunk length in minutes
+  --language <CODE>       Source language
+  --audio-stream <INDEX>  Audio stream to transcribe (default: matches the language)
+  --target <CODE>         Also write a <name>.<CODE>.srt translation
+  --translation-model <M> Chat model used for translation
+  --translation-url <URL> Chat-completions base URL used for translation
+  --translation <MODE>    chat, or audio to let the provider translate English targets
+  --output <FOLDER>       Output folder (default: next to each video)
+  --format <FORMAT>       Subtitle format: srt, ass, vtt
+  --mux <CONTAINER>       Also write <name>.subtitled.<mkv|mp4> with the subtitles as tracks
+  --ffmpeg <PATH>         ffmpeg binary (default: bundled sidecar, then PATH)
+  --ffprobe <PATH>        ffprobe binary (default: bundled sidecar, then PATH)
+  --whisper-cli <PATH>    whisper.cpp binary (default: bundled sidecar, then PATH)
+  --json                  Print machine-readable JSON on stdout
+  -h, --help              Print this help
+
+The API key is read from VIDEO_TRANSCRIPT_API_KEY or OPENAI_API_KEY when the settings
+file does not provide one.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Probe,
+    Chunks,
+    Cost,
+    Transcribe,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    settings_path: Option<PathBuf>,
+    settings: Vec<(&'static str, Value)>,
+    overrides: JobOverrides,
+    sidecars: Sidecars,
+    mux: Option<MuxContainer>,
+    json: bool,
+    videos: Vec<String>,
+}
+
+fn parse_value<T: DeserializeOwned>(flag: &str, value: &str) -> Result<T, String> {
+    serde_json::from_value(Value::String(value.to_string()))
+        .map_err(|_| format!("Invalid value for {flag}: {value}"))
+}
+
+fn parse_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<Option<(Command, Options)>, String> {
+    let mut args = args.into_iter();
+    let command = match args.next().as_deref() {
+        None | Some("-h" | "--help" | "help") => return Ok(None),
+        Some("probe") => Command::Probe,
+        Some("chunks") => Command::Chunks,
+        Some("cost") => Command::Cost,
+        Some("transcribe") => Command::Transcribe,
+        Some(other) => return Err(format!("Unknown command: {other}")),
+    };
+
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') {
+            options.videos.push(arg);
+            continue;
+        }
+
+        let flag = arg.as_str();
+        match flag {
+            "-h" | "--help" => return Ok(None),
+            "--json" => {
+                options.json = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        match flag {
+            "--settings" => options.settings_path = Some(PathBuf::from(value)),
+            "--provider" => options.settings.push(("provider", Value::String(value))),
+            "--model" => options
+                .settings
+                .push(("whisperCppModelPath", Value::String(value))),
+            "--translation-model" => options
+                .settings
+                .push(("translationModel", Value::String(value))),
+            "--translation-url" => options
+                .settings
+                .push(("translationApiBaseUrl", Value::String(value))),
+            "--translation" => options
+                .settings
+                .push(("translationMode", Value::String(value))),
+            "--chunking" => options
+                .settings
+                .push(("chunkingMode", Value::String(value))),
+            "--chunk-minutes" => {
+                let minutes: u32 = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value for {flag}: {value}"))?;
+                options
+                    .settings
+                    .push(("chunkDurationMinutes", minutes.into()));
+            }
+            "--language" => options.overrides.source_language = Some(value),
+            "--audio-stream" => {
+                let index: u32 = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value for {flag}: {value}"))?;
+                options.overrides.audio_stream_index = Some(index);
+            }
+            "--target" => options.overrides.target_language = Some(value),
+            "--output" => options.overrides.output_folder = Some(value),
+            "--format" => options.overrides.output_format = Some(parse_value(flag, &value)?),
+            "--mux" => options.mux = Some(parse_value(flag, &value)?),
+            "--ffmpeg" => options.sidecars.ffmpeg = Some(PathBuf::from(value)),
+            "--ffprobe" => options.sidecars.ffprobe = Some(PathBuf::from(value)),
+            "--whisper-cli" => options.sidecars.whisper_cli = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown option: {flag}")),
+        }
+    }
+
+    if options.videos.is_empty() {
+        return Err("No video files given.".to_string());
+    }
+
+    Ok(Some((command, options)))
+}
+
+/// Applies command line values on top of the settings by round-tripping through the same
+/// JSON shape the app saves, so every value is validated the way the app validates it.
+fn apply_settings_overrides(
+    settings: AppSettings,
+    overrides: &[(&'static str, Value)],
+) -> Result<AppSettings, String> {
+    let mut value = serde_json::to_value(settings).map_err(|error| error.to_string())?;
+    for (key, override_value) in overrides {
+        value[*key] = override_value.clone();
+    }
+
+    serde_json::from_value(value).map_err(|error| format!("Invalid option: {error}"))
+}
+
+async fn load_settings(options: &Options) -> Result<AppSettings, String> {
+    let settings = match &options.settings_path {
+        Some(path) => {
+            let content = fs::read_to_string(path)
+                .await
+                .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+            serde_json::from_str(&content)
+                .map_err(|error| format!("Failed to parse {}: {error}", path.display()))?
+        }
+        None => AppSettings::default(),
+    };
+    let mut settings = apply_settings_overrides(settings, &options.settings)?;
+
+    if settings.api_key.trim().is_empty() {
+        if let Some(api_key) = API_KEY_ENV_VARS
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty())
+        {
+            settings.api_key = api_key;
+        }
+    }
+
+    settings.validate()?;
+    Ok(settings)
+}
+
+/// Prefers the bundled sidecars like the app does, then whatever is on `PATH`.
+fn resolve_sidecars(mut sidecars: Sidecars) -> Sidecars {
+    if sidecars.ffmpeg().is_err() {
+        sidecars.ffmpeg = Some(PathBuf::from("ffmpeg"));
+    }
+
+    sidecars
+}
+
+/// Prints progress on stderr. On a terminal the line is redrawn in place; otherwise only
+/// stage changes are printed so logs stay readable.
+struct ConsoleReporter {
+    interactive: bool,
+    last_step: Mutex<Option<(ProcessingStage, u32)>>,
+}
+
+impl ConsoleReporter {
+    fn new() -> Self {
+        Self {
+            interactive: std::io::stderr().is_terminal(),
+            last_step: Mutex::new(None),
+        }
+    }
+
+    fn finish_line(&self) {
+        if self.interactive {
+            eprintln!();
+        }
+    }
+}
+
+impl ProgressReporter for ConsoleReporter {
+    fn progress(&self, progress: ProcessingProgress) {
+        if self.interactive {
+            eprint!(
+                "\r\x1b[2K[{:>3.0}%] {}",
+                progress.percentage, progress.message
+            );
+            return;
+        }
+
+        let step = Some((progress.stage, progress.chunk_number));
+        let mut last_step = self
+            .last_step
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *last_step != step {
+            *last_step = step;
+            eprintln!("[{:>3.0}%] {}", progress.percentage, progress.message);
+        }
+    }
+
+    fn retry(&self, retry: ProcessingRetry) {
+        self.finish_line();
+        eprintln!(
+            "Chunk {}/{} failed, retry {}/{} in {:.0}s: {}",
+            retry.chunk_number,
+            retry.total_chunks,
+            retry.retry,
+            retry.max_retries,
+            retry.delay_seconds,
+            retry.error
+        );
+    }
+}
+
+struct Session {
+    settings: AppSettings,
+    overrides: JobOverrides,
+    sidecars: Sidecars,
+    mux: Option<MuxContainer>,
+    reporter: ConsoleReporter,
+    cancel: CancellationToken,
+}
+
+impl Session {
+    async fn probe(&self, video: &str) -> Result<VideoInfo, String> {
+        probe_video(&self.sidecars, video.to_string(), Some(&self.cancel)).await
+    }
+
+    /// Returns the written files: the transcript, its translation if any, then the subtitled
+    /// video when muxing was requested.
+    async fn transcribe(&self, video: &str) -> Result<Vec<PathBuf>, String> {
+        let settings = &self.settings;
+        let mut config = self.overrides.resolve(video, settings);
+        providers::ensure_credentials(settings)?;
+        if chat_translation_target(&config, settings).is_some() {
+            translation::ensure_credentials(settings)?;
+        }
+        let translate = translates_audio_to_english(&config, settings);
+        let provider = providers::provider_from_settings(&self.sidecars, settings)
+            .map_err(|error| error.to_string())?;
+        if translate {
+            ensure_can_translate_audio(provider.as_ref())?;
+        }
+
+        let video_info = self.probe(video).await?;
+        let audio_stream = resolve_audio_stream(
+            &video_info.audio_streams,
+            config.audio_stream_index,
+            &config.source_language,
+        )?;
+        config.audio_stream_index = Some(audio_stream);
+        let chunks = plan_chunks(
+            &self.reporter,
+            &self.sidecars,
+            settings,
+            &video_info,
+            Some(audio_stream),
+            &self.cancel,
+        )
+        .await?;
+        let output_format = config.output_format.unwrap_or_default();
+        let output_path = resolve_output_path(
+            Path::new(&video_info.path),
+            config.output_folder.as_deref(),
+            output_format,
+        )?;
+
+        let temp_root = std::env::temp_dir().join(TEMP_DIR_NAME);
+        let job_dir = temp::create_job_dir(&temp_root, &new_job_id()).await?;
+        let context = PipelineContext {
+            reporter: &self.reporter,
+            sidecars: &self.sidecars,
+            provider: provider.as_ref(),
+            config: &config,
+            translate,
+            retry_policy: RetryPolicy::new(settings.max_retries),
+            job_dir: &job_dir,
+            cancel: &self.cancel,
+        };
+
+        let mut transcripts = Vec::with_capacity(chunks.len());
+        let mut failure = None;
+        for (index, chunk) in chunks.iter().enumerate() {
+            match transcribe_chunk(&context, &video_info.path, index, chunks.len(), chunk).await {
+                Ok(segments) => transcripts.push(segments),
+                Err(error) => {
+                    failure = Some(error);
+                    break;
+                }
+            }
+        }
+        temp::cleanup_job_dir(&job_dir).await;
+        self.reporter.finish_line();
+        if let Some(error) = failure {
+            return Err(error);
+        }
+        ensure_not_cancelled(&self.cancel)?;
+
+        let segments = srt::merge_chunk_segments(&chunks, &transcripts)?;
+        let subtitles = SubtitleOutput {
+            format: output_format,
+            preset: settings.active_style_preset(),
+            video_path: &video_info.path,
+            provider: provider.name(),
+            source_language: &config.source_language,
+        };
+        let output_language = if translate {
+            "en"
+        } else {
+            &config.source_language
+        };
+        let content = subtitles.render(&segments, output_language);
+        write_subtitle_file(&output_path, output_format, &content).await?;
+        let mut written = vec![output_path];
+
+        if let Some(target) = chat_translation_target(&config, settings) {
+            let translated = translate_segments(
+                &self.reporter,
+                settings,
+                &config.source_language,
+                target,
+                &segments,
+                &self.cancel,
+            )
+            .await;
+            self.reporter.finish_line();
+            let path = translated_output_path(&written[0], target);
+            let content = subtitles.render(&translated?, target);
+            write_subtitle_file(&path, output_format, &content).await?;
+            written.push(path);
+        }
+
+        if let Some(container) = self.mux {
+            let languages = [Some(output_language.to_string()), config.target_language];
+            let tracks: Vec<MuxTrack> = written
+                .iter()
+                .zip(languages)
+                .map(|(path, language)| MuxTrack {
+                    path: path.to_string_lossy().to_string(),
+                    language,
+                })
+                .collect();
+            let muxed = mux_subtitle_tracks(
+                &self.reporter,
+                &self.sidecars,
+                &video_info,
+                &tracks,
+                Some(container),
+                config.output_folder.as_deref(),
+                &self.cancel,
+            )
+            .await;
+            self.reporter.finish_line();
+            written.push(muxed?);
+        }
+
+        Ok(written)
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{json}"),
+        Err(error) => eprintln!("Failed to serialize output: {error}"),
+    }
+}
+
+fn print_video_info(info: &VideoInfo) {
+    let resolution = match (info.width, info.height) {
+        (Some(width), Some(height)) => format!(", {width}x{height}"),
+        _ => String::new(),
+    };
+    let frame_rate = match info.frame_rate {
+        Some(rate) if info.variable_frame_rate => format!(", ~{rate:.2} fps VFR"),
+        Some(rate) => format!(", {rate:.2} fps"),
+        None => String::new(),
+    };
+    let rotation = match info.rotation {
+        0 => String::new(),
+        degrees => format!(", rotated {degrees}°"),
+    };
+    let bit_rate = info
+        .bit_rate
+        .map(|bits| format!(", {} kb/s", bits / 1000))
+        .unwrap_or_default();
+    println!(
+        "{}: {:.1}s, {} ({}{resolution}{frame_rate}{rotation}), {} bytes{bit_rate}",
+        info.path, info.duration_seconds, info.format_name, info.codec_name, info.size_bytes
+    );
+    for stream in &info.audio_streams {
+        let channels = match (&stream.channel_layout, stream.channels) {
+            (Some(layout), _) => format!(", {layout}"),
+            (None, Some(channels)) => format!(", {channels} ch"),
+            (None, None) => String::new(),
+        };
+        let sample_rate = stream
+            .sample_rate
+            .map(|rate| format!(", {rate} Hz"))
+            .unwrap_or_default();
+        let language = stream.language.as_deref().unwrap_or("und");
+        let title = stream
+            .title
+            .as_deref()
+            .map(|title| format!(" \"{title}\""))
+            .unwrap_or_default();
+        let default = if stream.is_default { " (default)" } else { "" };
+        println!(
+            "  audio {}: {language}, {}{channels}{sample_rate}{title}{default}",
+            stream.index, stream.codec_name
+        );
+    }
+    for stream in &info.subtitle_streams {
+        let language = stream.language.as_deref().unwrap_or("und");
+        let forced = if stream.is_forced { " (forced)" } else { "" };
+        println!(
+            "  subtitle {}: {language}, {}{forced}",
+            stream.index, stream.codec_name
+        );
+    }
+    if !info.chapters.is_empty() {
+        println!("  {} chapters", info.chapters.len());
+    }
+}
+
+async fn run_command(
+    command: Command,
+    session: &Session,
+    video: &str,
+    json: bool,
+) -> Result<(), String> {
+    match command {
+        Command::Probe => {
+            let info = session.probe(video).await?;
+            if json {
+                print_json(&info);
+            } else {
+                print_video_info(&info);
+            }
+        }
+        Command::Chunks => {
+            let info = session.probe(video).await?;
+            let config = session.overrides.resolve(video, &session.settings);
+            let audio_stream = resolve_audio_stream(
+                &info.audio_streams,
+                config.audio_stream_index,
+                &config.source_language,
+            )?;
+            let chunks = plan_chunks(
+                &session.reporter,
+                &session.sidecars,
+                &session.settings,
+                &info,
+                Some(audio_stream),
+                &session.cancel,
+            )
+            .await?;
+            session.reporter.finish_line();
+            if json {
+                print_json(&chunks);
+            } else {
+                println!("{}: {} chunks", info.path, chunks.len());
+                for chunk in &chunks {
+                    println!(
+                        "  {:>3}  {:>9.2}s - {:>9.2}s  (~{} bytes)",
+                        chunk.chunk_number,
+                        chunk.start_time_seconds,
+                        chunk.end_time_seconds,
+                        chunk.expected_size_bytes
+                    );
+                }
+            }
+        }
+        Command::Cost => {
+            let info = session.probe(video).await?;
+            let estimate = estimate_cost_for(&session.settings, info.duration_seconds)?;
+            if json {
+                print_json(&estimate);
+            } else {
+                println!(
+                    "{}: {:.1} min, {}",
+                    info.path, estimate.duration_minutes, estimate.formatted_cost
+                );
+            }
+        }
+        Command::Transcribe => {
+            for output_path in session.transcribe(video).await? {
+                println!("{}", output_path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_async(command: Command, options: Options) -> ExitCode {
+    let settings = match load_settings(&options).await {
+        Ok(settings) => settings,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let session = Session {
+        settings,
+        overrides: options.overrides,
+        sidecars: resolve_sidecars(options.sidecars),
+        mux: options.mux,
+        reporter: ConsoleReporter::new(),
+        cancel: CancellationToken::new(),
+    };
+
+    let cancel = session.cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            cancel.cancel();
+        }
+    });
+
+    let mut failed = false;
+    for video in &options.videos {
+        if let Err(error) = run_command(command, &session, video, options.json).await {
+            session.reporter.finish_line();
+            eprintln!("error: {video}: {error}");
+            failed = true;
+        }
+        if session.cancel.is_cancelled() {
+            break;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+pub fn run() -> ExitCode {
+    let (command, options) = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime.block_on(run_async(command, options)),
+        Err(error) => {
+            eprintln!("error: failed to start async runtime: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_settings_overrides, parse_args, Command};
+    use crate::models::settings::{AppSettings, ChunkingMode, ProviderKind, SubtitleFormat};
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_command_options_and_videos() {
+        let (command, options) = parse_args(args(&[
+            "transcribe",
+            "--provider",
+            "whispercpp",
+            "--language",
+            "de",
+            "--format",
+            "srt",
+            "--ffmpeg",
+            "/opt/ffmpeg",
+            "a.mp4",
+            "b.mkv",
+        ]))
+        .expect("valid arguments")
+        .expect("a command");
+
+        assert_eq!(command, Command::Transcribe);
+        assert_eq!(options.videos, ["a.mp4", "b.mkv"]);
+        assert_eq!(options.overrides.source_language.as_deref(), Some("de"));
+        assert_eq!(options.overrides.output_format, Some(SubtitleFormat::Srt));
+        assert_eq!(
+            options.sidecars.ffmpeg.as_deref(),
+            Some(std::path::Path::new("/opt/ffmpeg"))
+        );
+
+        let settings = apply_settings_overrides(AppSettings::default(), &options.settings)
+            .expect("valid settings");
+        assert_eq!(settings.provider, ProviderKind::WhisperCpp);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(parse_args(args(&["transcribe"])).is_err());
+        assert!(parse_args(args(&["convert", "a.mp4"])).is_err());
+        assert!(parse_args(args(&["probe", "a.mp4", "--ffprobe"])).is_err());
+        assert!(parse_args(args(&["cost", "--chunk-minutes", "ten", "a.mp4"])).is_err());
+        assert!(parse_args(args(&["probe", "--help"]))
+            .expect("help")
+            .is_none());
+
+        let (_, options) = parse_args(args(&["chunks", "--chunking", "sometimes", "a.mp4"]))
+            .expect("valid arguments")
+            .expect("a command");
+        assert!(apply_settings_overrides(AppSettings::default(), &options.settings).is_err());
+
+        let (_, options) = parse_args(args(&["chunks", "--chunking", "overlap", "a.mp4"]))
+            .expect("valid arguments")
+            .expect("a command");
+        let settings = apply_settings_overrides(AppSettings::default(), &options.settings)
+            .expect("valid settings");
+        assert_eq!(settings.chunking_mode, ChunkingMode::Overlap);
+    }
+}
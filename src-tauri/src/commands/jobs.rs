@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+
+use crate::commands::queue::queued_job_ids;
+use crate::models::job::{unix_timestamp, JobManifest, JobSummary};
+
+const JOBS_DIR_NAME: &str = "jobs";
+const MANIFEST_EXTENSION: &str = "json";
+
+fn jobs_dir(app: &AppHandle) -> Result<PathBuf> {
+    let mut dir = app
+        .path()
+        .app_data_dir()
+        .context("failed to resolve app data directory")?;
+
+    dir.push(JOBS_DIR_NAME);
+    Ok(dir)
+}
+
+fn manifest_path(app: &AppHandle, job_id: &str) -> Result<PathBuf> {
+    if job_id.is_empty()
+        || !job_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        anyhow::bail!("invalid job id: {job_id}");
+    }
+
+    Ok(jobs_dir(app)?.join(format!("{job_id}.{MANIFEST_EXTENSION}")))
+}
+
+pub(crate) async fn save_manifest(app: &AppHandle, manifest: &mut JobManifest) -> Result<()> {
+    manifest.updated_at = unix_timestamp();
+
+    let file_path = manifest_path(app, &manifest.id)?;
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .context("failed to create jobs directory")?;
+    }
+
+    let content =
+        serde_json::to_string_pretty(manifest).context("failed to serialize job manifest")?;
+    let temp_path = file_path.with_extension("tmp");
+
+    fs::write(&temp_path, content)
+        .await
+        .with_context(|| format!("failed to write job manifest {}", temp_path.display()))?;
+    fs::rename(&temp_path, &file_path)
+        .await
+        .with_context(|| format!("failed to replace job manifest {}", file_path.display()))?;
+
+    Ok(())
+}
+
+pub(crate) async fn load_manifest(app: &AppHandle, job_id: &str) -> Result<JobManifest> {
+    let file_path = manifest_path(app, job_id)?;
+    let content = fs::read_to_string(&file_path)
+        .await
+        .with_context(|| format!("failed to read job manifest {}", file_path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse job manifest {}", file_path.display()))
+}
+
+pub(crate) async fn delete_manifest(app: &AppHandle, job_id: &str) -> Result<()> {
+    let file_path = manifest_path(app, job_id)?;
+
+    match fs::remove_file(&file_path).await {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error)
+            .with_context(|| format!("failed to delete job manifest {}", file_path.display())),
+    }
+}
+
+async fn read_manifests(app: &AppHandle) -> Result<Vec<JobManifest>> {
+    let dir = jobs_dir(app)?;
+    if !fs::try_exists(&dir).await.unwrap_or(false) {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = fs::read_dir(&dir)
+        .await
+        .with_context(|| format!("failed to read jobs directory {}", dir.display()))?;
+    let mut manifests = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(MANIFEST_EXTENSION) {
+            continue;
+        }
+
+        let parsed = match fs::read_to_string(&path).await {
+            Ok(content) => {
+                serde_json::from_str::<JobManifest>(&content).map_err(anyhow::Error::from)
+            }
+            Err(error) => Err(error.into()),
+        };
+
+        match parsed {
+            Ok(manifest) => manifests.push(manifest),
+            Err(error) => eprintln!("[jobs] skipping {}: {error}", path.display()),
+        }
+    }
+
+    manifests.sort_by_key(|manifest| std::cmp::Reverse(manifest.updated_at));
+    Ok(manifests)
+}
+
+#[tauri::command]
+pub async fn list_unfinished_jobs(app: AppHandle) -> Result<Vec<JobSummary>, String> {
+    let manifests = read_manifests(&app)
+        .await
+        .map_err(|error| error.to_string())?;
+    let queued = queued_job_ids(&app).await;
+
+    Ok(manifests
+        .iter()
+        .filter(|manifest| !queued.contains(&manifest.id))
+        .map(JobManifest::summary)
+        .collect())
+}
+
+#[tauri::command]
+pub async fn discard_job(app: AppHandle, job_id: String) -> Result<(), String> {
+    delete_manifest(&app, &job_id)
+        .await
+        .map_err(|error| error.to_string())
+}
@@ -1,3 +1,13 @@
+#[cfg(feature = "gui")]
+pub mod jobs;
+#[cfg(feature = "gui")]
 pub mod language;
+pub mod processing;
+#[cfg(feature = "gui")]
+pub mod queue;
+#[cfg(feature = "gui")]
 pub mod settings;
+pub mod subtitles;
 pub mod video;
+#[cfg(feature = "gui")]
+pub mod watch;
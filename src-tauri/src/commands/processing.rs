@@ -0,0 +1,879 @@
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "gui")]
+use serde::Serialize;
+#[cfg(feature = "gui")]
+use tauri::{AppHandle, Emitter, State};
+use tokio_util::sync::CancellationToken;
+
+#[cfg(feature = "gui")]
+use crate::commands::jobs;
+#[cfg(feature = "gui")]
+use crate::commands::settings::get_settings;
+use crate::commands::video::{
+    calculate_chunks_for_settings, calculate_chunks_with_silences, ensure_chunks_fit_upload_limit,
+    planned_chunk_minutes, shortened_chunks_notice, ChunkInfo, VideoInfo,
+};
+#[cfg(feature = "gui")]
+use crate::commands::video::{path_to_string, probe_video, resolve_audio_stream};
+#[cfg(feature = "gui")]
+use crate::models::job::{new_job_id, ChunkStatus, JobManifest};
+use crate::models::language::language_name;
+use crate::models::processing::{
+    ProcessingConfig, ProcessingProgress, ProcessingRetry, ProcessingStage, CANCELLED_MESSAGE,
+};
+#[cfg(feature = "gui")]
+use crate::models::processing::{
+    ProcessingJobState, ProcessingResult, CANCELLED_EVENT, COMPLETE_EVENT, ERROR_EVENT,
+    PROGRESS_EVENT, RETRY_EVENT,
+};
+#[cfg(feature = "gui")]
+use crate::models::queue::{QueueJobEvent, QUEUE_PROGRESS_EVENT, QUEUE_RETRY_EVENT};
+use crate::models::settings::{AppSettings, ChunkingMode, SubtitleFormat, TranslationMode};
+use crate::models::style::StylePreset;
+use crate::models::transcript::Segment;
+use crate::providers::translation::{self, TranslationClient, TranslationItem};
+use crate::providers::whisper::ResponseFormat;
+use crate::providers::{self, TranscriptionProvider};
+use crate::utils::ffmpeg::{AudioInput, FFmpegProgress, Sidecars};
+use crate::utils::retry::{retry_with_backoff, RetryAttempt, RetryPolicy};
+#[cfg(feature = "gui")]
+use crate::utils::subtitle_file::write_subtitle_file;
+use crate::utils::vtt::{self, VttOptions};
+use crate::utils::{ass, ffmpeg, srt, temp};
+
+const STEPS_PER_CHUNK: f64 = 2.0;
+const CHUNK_PROGRESS_SHARE: f64 = 95.0;
+const TRANSLATION_PROGRESS_SHARE: f64 = 4.0;
+
+/// Receives pipeline progress. The app forwards it to the webview, the CLI prints it.
+pub(crate) trait ProgressReporter: Sync {
+    fn progress(&self, progress: ProcessingProgress);
+
+    fn retry(&self, retry: ProcessingRetry);
+}
+
+/// Routes pipeline events either to the single-video listeners or, for queued jobs, to the
+/// queue listeners tagged with the job id.
+#[cfg(feature = "gui")]
+#[derive(Clone, Copy)]
+pub(crate) struct JobEvents<'a> {
+    app: &'a AppHandle,
+    queue_job_id: Option<&'a str>,
+}
+
+#[cfg(feature = "gui")]
+impl<'a> JobEvents<'a> {
+    pub(crate) fn direct(app: &'a AppHandle) -> Self {
+        Self {
+            app,
+            queue_job_id: None,
+        }
+    }
+
+    pub(crate) fn queued(app: &'a AppHandle, job_id: &'a str) -> Self {
+        Self {
+            app,
+            queue_job_id: Some(job_id),
+        }
+    }
+
+    fn emit<T: Serialize + Clone>(&self, event: &str, queue_event: &str, payload: T) {
+        let emitted = match self.queue_job_id {
+            Some(job_id) => self.app.emit(
+                queue_event,
+                QueueJobEvent {
+                    job_id: job_id.to_string(),
+                    payload,
+                },
+            ),
+            None => self.app.emit(event, payload),
+        };
+
+        if let Err(error) = emitted {
+            eprintln!("[processing] failed to emit {event}: {error}");
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+impl ProgressReporter for JobEvents<'_> {
+    fn progress(&self, progress: ProcessingProgress) {
+        self.emit(PROGRESS_EVENT, QUEUE_PROGRESS_EVENT, progress);
+    }
+
+    fn retry(&self, retry: ProcessingRetry) {
+        self.emit(RETRY_EVENT, QUEUE_RETRY_EVENT, retry);
+    }
+}
+
+pub(crate) struct PipelineContext<'a> {
+    pub(crate) reporter: &'a dyn ProgressReporter,
+    pub(crate) sidecars: &'a Sidecars,
+    pub(crate) provider: &'a dyn TranscriptionProvider,
+    pub(crate) config: &'a ProcessingConfig,
+    pub(crate) translate: bool,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) job_dir: &'a Path,
+    pub(crate) cancel: &'a CancellationToken,
+}
+
+pub(crate) fn emit_progress(
+    reporter: &dyn ProgressReporter,
+    stage: ProcessingStage,
+    chunk_number: u32,
+    total_chunks: u32,
+    percentage: f64,
+    message: String,
+) {
+    let progress = ProcessingProgress {
+        stage,
+        chunk_number,
+        total_chunks,
+        percentage,
+        message,
+    };
+
+    reporter.progress(progress);
+}
+
+fn emit_retry(
+    reporter: &dyn ProgressReporter,
+    chunk_number: u32,
+    total_chunks: u32,
+    attempt: &RetryAttempt,
+) {
+    let retry = ProcessingRetry {
+        chunk_number,
+        total_chunks,
+        retry: attempt.retry,
+        max_retries: attempt.max_retries,
+        delay_seconds: attempt.delay.as_secs_f64(),
+        error: attempt.error.clone(),
+    };
+
+    reporter.retry(retry);
+}
+
+pub(crate) fn ensure_not_cancelled(cancel: &CancellationToken) -> Result<(), String> {
+    if cancel.is_cancelled() {
+        return Err(CANCELLED_MESSAGE.to_string());
+    }
+
+    Ok(())
+}
+
+fn chunk_percentage(chunk_index: usize, step: f64, total_chunks: usize) -> f64 {
+    let completed_steps = chunk_index as f64 * STEPS_PER_CHUNK + step;
+    completed_steps / (total_chunks as f64 * STEPS_PER_CHUNK) * CHUNK_PROGRESS_SHARE
+}
+
+fn format_step_progress(
+    label: &str,
+    chunk_number: u32,
+    total_chunks: u32,
+    progress: &FFmpegProgress,
+) -> String {
+    let percent = (progress.fraction * 100.0).round();
+    match progress.speed {
+        Some(speed) => format!("{label} {chunk_number}/{total_chunks} ({percent}%, {speed:.1}x)"),
+        None => format!("{label} {chunk_number}/{total_chunks} ({percent}%)"),
+    }
+}
+
+/// The folder subtitles for `video_path` are written to: the chosen folder, or the video's
+/// own folder when none is set.
+pub(crate) fn resolve_output_folder(
+    video_path: &Path,
+    output_folder: Option<&str>,
+) -> Result<PathBuf, String> {
+    let folder = match output_folder.filter(|folder| !folder.trim().is_empty()) {
+        Some(folder) => PathBuf::from(folder),
+        None => video_path
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| "Unable to determine the video's folder.".to_string())?,
+    };
+
+    if !folder.is_dir() {
+        return Err(format!(
+            "Output folder does not exist: {}",
+            folder.display()
+        ));
+    }
+
+    Ok(folder)
+}
+
+pub(crate) fn resolve_output_path(
+    video_path: &Path,
+    output_folder: Option<&str>,
+    format: SubtitleFormat,
+) -> Result<PathBuf, String> {
+    let folder = resolve_output_folder(video_path, output_folder)?;
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| "Unable to determine video file name.".to_string())?;
+
+    Ok(folder.join(format!("{stem}.{}", format.extension())))
+}
+
+/// The language the finished transcript should be translated into, if any.
+pub(crate) fn translation_target(config: &ProcessingConfig) -> Option<&str> {
+    config
+        .target_language
+        .as_deref()
+        .map(str::trim)
+        .filter(|target| !target.is_empty() && *target != config.source_language)
+}
+
+/// Whether the provider should translate the audio straight into English instead of
+/// transcribing it in the source language.
+pub(crate) fn translates_audio_to_english(
+    config: &ProcessingConfig,
+    settings: &AppSettings,
+) -> bool {
+    settings.translation_mode == TranslationMode::Audio && translation_target(config) == Some("en")
+}
+
+/// The target the chat translation stage has to produce, if any.
+pub(crate) fn chat_translation_target<'a>(
+    config: &'a ProcessingConfig,
+    settings: &AppSettings,
+) -> Option<&'a str> {
+    translation_target(config).filter(|_| !translates_audio_to_english(config, settings))
+}
+
+pub(crate) fn ensure_can_translate_audio(
+    provider: &dyn TranscriptionProvider,
+) -> Result<(), String> {
+    if !provider.capabilities().translation {
+        return Err(format!(
+            "{} does not support audio translation.",
+            provider.name()
+        ));
+    }
+
+    Ok(())
+}
+
+/// `<name>.<ext>` becomes `<name>.<target>.<ext>`, next to the source-language file.
+pub(crate) fn translated_output_path(output_path: &Path, target_language: &str) -> PathBuf {
+    let stem = output_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+
+    match output_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some(extension) => {
+            output_path.with_file_name(format!("{stem}.{target_language}.{extension}"))
+        }
+        None => output_path.with_file_name(format!("{stem}.{target_language}")),
+    }
+}
+
+/// Translates the transcript in batches through the chat-completions endpoint. Timings are
+/// copied from the source segments, so only the text changes.
+pub(crate) async fn translate_segments(
+    reporter: &dyn ProgressReporter,
+    settings: &AppSettings,
+    source_language: &str,
+    target_language: &str,
+    segments: &[Segment],
+    cancel: &CancellationToken,
+) -> Result<Vec<Segment>, String> {
+    let client = TranslationClient::new(settings).map_err(|error| error.to_string())?;
+    let retry_policy = RetryPolicy::new(settings.max_retries);
+    let source_name = language_name(source_language);
+    let target_name = language_name(target_language);
+    let batches = translation::batch_ranges(segments);
+    let total_batches = batches.len() as u32;
+    let mut translated = Vec::with_capacity(segments.len());
+
+    for (index, range) in batches.into_iter().enumerate() {
+        let batch_number = index as u32 + 1;
+        ensure_not_cancelled(cancel)?;
+        emit_progress(
+            reporter,
+            ProcessingStage::Translating,
+            batch_number,
+            total_batches,
+            CHUNK_PROGRESS_SHARE
+                + TRANSLATION_PROGRESS_SHARE * index as f64 / f64::from(total_batches),
+            format!("Translating to {target_name} {batch_number}/{total_batches}"),
+        );
+
+        let items = range
+            .clone()
+            .map(|position| TranslationItem {
+                id: position + 1,
+                text: segments[position].text.clone(),
+            })
+            .collect::<Vec<_>>();
+        let request = retry_with_backoff(
+            &retry_policy,
+            || client.translate(&source_name, &target_name, &items),
+            |attempt| emit_retry(reporter, batch_number, total_batches, attempt),
+        );
+        let texts = tokio::select! {
+            result = request => result
+                .map_err(|error| format!("Failed to translate batch {batch_number}: {error}"))?,
+            _ = cancel.cancelled() => return Err(CANCELLED_MESSAGE.to_string()),
+        };
+
+        translated.extend(
+            segments[range]
+                .iter()
+                .zip(texts)
+                .map(|(segment, text)| Segment {
+                    start: segment.start,
+                    end: segment.end,
+                    text,
+                    words: Vec::new(),
+                }),
+        );
+    }
+
+    Ok(translated)
+}
+
+pub(crate) async fn transcribe_chunk(
+    context: &PipelineContext<'_>,
+    input_path: &str,
+    index: usize,
+    total: usize,
+    chunk: &ChunkInfo,
+) -> Result<Vec<Segment>, String> {
+    let reporter = context.reporter;
+    let cancel = context.cancel;
+    let total_chunks = total as u32;
+    let chunk_number = chunk.chunk_number;
+    let audio_format = context.provider.capabilities().audio_format;
+    let audio_chunk = context
+        .job_dir
+        .join(format!("chunk_{chunk_number:03}.{audio_format}"));
+
+    ensure_not_cancelled(cancel)?;
+    emit_progress(
+        reporter,
+        ProcessingStage::Extracting,
+        chunk_number,
+        total_chunks,
+        chunk_percentage(index, 0.0, total),
+        format!("Extracting audio {chunk_number}/{total_chunks}"),
+    );
+    ffmpeg::extract_chunk_audio(
+        context.sidecars,
+        AudioInput {
+            path: input_path,
+            stream: context.config.audio_stream_index,
+        },
+        chunk.padded_start_seconds,
+        chunk.padded_duration_seconds(),
+        &audio_chunk,
+        cancel,
+        |progress| {
+            emit_progress(
+                reporter,
+                ProcessingStage::Extracting,
+                chunk_number,
+                total_chunks,
+                chunk_percentage(index, progress.fraction, total),
+                format_step_progress("Extracting audio", chunk_number, total_chunks, &progress),
+            )
+        },
+    )
+    .await
+    .map_err(|error| format!("Failed to extract audio for chunk {chunk_number}: {error}"))?;
+
+    ensure_not_cancelled(cancel)?;
+    emit_progress(
+        reporter,
+        ProcessingStage::Transcribing,
+        chunk_number,
+        total_chunks,
+        chunk_percentage(index, 1.0, total),
+        format!("Transcribing {chunk_number}/{total_chunks}"),
+    );
+    let request = retry_with_backoff(
+        &context.retry_policy,
+        || async {
+            if context.translate {
+                context
+                    .provider
+                    .translate(&audio_chunk, ResponseFormat::VerboseJson)
+                    .await
+            } else {
+                context
+                    .provider
+                    .transcribe(
+                        &audio_chunk,
+                        &context.config.source_language,
+                        ResponseFormat::VerboseJson,
+                    )
+                    .await
+            }
+        },
+        |attempt| emit_retry(reporter, chunk_number, total_chunks, attempt),
+    );
+    let transcription = tokio::select! {
+        result = request => result
+            .map_err(|error| format!("Failed to transcribe chunk {chunk_number}: {error}"))?,
+        _ = cancel.cancelled() => return Err(CANCELLED_MESSAGE.to_string()),
+    };
+    temp::remove_file(&audio_chunk).await;
+
+    Ok(transcription.segments)
+}
+
+#[cfg(feature = "gui")]
+async fn transcribe_pending_chunks(
+    app: &AppHandle,
+    context: &PipelineContext<'_>,
+    manifest: &mut JobManifest,
+) -> Result<(), String> {
+    let total = manifest.chunks.len();
+    let input_path = manifest.input_path.clone();
+
+    for index in 0..total {
+        if manifest.chunks[index].status == ChunkStatus::Completed {
+            continue;
+        }
+
+        let chunk = manifest.chunks[index].chunk.clone();
+        let outcome = transcribe_chunk(context, &input_path, index, total, &chunk).await;
+        let record = &mut manifest.chunks[index];
+
+        let failure = match outcome {
+            Ok(segments) => {
+                record.status = ChunkStatus::Completed;
+                record.segments = segments;
+                record.error = None;
+                None
+            }
+            Err(error) if context.cancel.is_cancelled() => Some(error),
+            Err(error) => {
+                record.status = ChunkStatus::Failed;
+                record.error = Some(error.clone());
+                manifest.last_error = Some(error.clone());
+                Some(error)
+            }
+        };
+
+        jobs::save_manifest(app, manifest)
+            .await
+            .map_err(|error| format!("Failed to save job progress: {error}"))?;
+
+        if let Some(error) = failure {
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+/// Everything the subtitle writers need besides the segments themselves.
+pub(crate) struct SubtitleOutput<'a> {
+    pub format: SubtitleFormat,
+    pub preset: StylePreset,
+    pub video_path: &'a str,
+    pub provider: &'a str,
+    pub source_language: &'a str,
+}
+
+impl SubtitleOutput<'_> {
+    /// `language` is the language of `segments`. It picks the preset's per-language style
+    /// and fills in the WebVTT `Language:` header.
+    pub(crate) fn render(&self, segments: &[Segment], language: &str) -> String {
+        match self.format {
+            SubtitleFormat::Srt => srt::generate_srt(segments),
+            SubtitleFormat::Ass => ass::generate_ass(segments, &self.preset, Some(language)),
+            SubtitleFormat::Vtt => {
+                let notes = self.notes(language);
+                let options = VttOptions {
+                    language: Some(language),
+                    style: Some(self.preset.style_for_language(Some(language))),
+                    notes: &notes,
+                };
+                vtt::generate_vtt(segments, &options)
+            }
+        }
+    }
+
+    fn notes(&self, language: &str) -> Vec<String> {
+        let video = Path::new(self.video_path);
+        let file_name = video
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.video_path.to_string());
+        let mut notes = vec![
+            format!("Source: {file_name}"),
+            format!(
+                "Transcribed from {} with {}",
+                language_name(self.source_language),
+                self.provider
+            ),
+        ];
+        if language != self.source_language {
+            notes.push(format!("Translated to {}", language_name(language)));
+        }
+
+        notes
+    }
+}
+
+#[cfg(feature = "gui")]
+async fn execute_job(
+    events: &JobEvents<'_>,
+    mut manifest: JobManifest,
+    settings: &AppSettings,
+    cancel: &CancellationToken,
+) -> Result<ProcessingResult, String> {
+    let app = events.app;
+    let config = manifest.config.clone();
+    let translate = translates_audio_to_english(&config, settings);
+    let sidecars = Sidecars::from_app(app);
+    let provider = providers::provider_from_settings(&sidecars, settings)
+        .map_err(|error| error.to_string())?;
+    if translate {
+        ensure_can_translate_audio(provider.as_ref())?;
+    }
+    eprintln!("[processing] transcribing with {}", provider.name());
+
+    let job_dir = temp::create_job_dir(&temp::jobs_root(app)?, &manifest.id).await?;
+    let context = PipelineContext {
+        reporter: events,
+        sidecars: &sidecars,
+        provider: provider.as_ref(),
+        config: &config,
+        translate,
+        retry_policy: RetryPolicy::new(settings.max_retries),
+        job_dir: &job_dir,
+        cancel,
+    };
+    let transcribed = transcribe_pending_chunks(app, &context, &mut manifest).await;
+    temp::cleanup_job_dir(&job_dir).await;
+    transcribed?;
+    ensure_not_cancelled(cancel)?;
+
+    let segments = srt::merge_chunk_segments(&manifest.chunk_infos(), &manifest.transcripts())?;
+    let total_chunks = manifest.chunks.len() as u32;
+    emit_progress(
+        events,
+        ProcessingStage::Generating,
+        total_chunks,
+        total_chunks,
+        CHUNK_PROGRESS_SHARE,
+        "Generating subtitles".to_string(),
+    );
+
+    let output_path = PathBuf::from(&manifest.output_path);
+    let output_format = config.output_format.unwrap_or_default();
+    let subtitles = SubtitleOutput {
+        format: output_format,
+        preset: settings.active_style_preset(),
+        video_path: &manifest.input_path,
+        provider: provider.name(),
+        source_language: &config.source_language,
+    };
+    let output_language = if translate {
+        "en"
+    } else {
+        &config.source_language
+    };
+    let content = subtitles.render(&segments, output_language);
+    write_subtitle_file(&output_path, output_format, &content).await?;
+
+    let translated_output_path = match chat_translation_target(&config, settings) {
+        Some(target) => {
+            let translated = translate_segments(
+                events,
+                settings,
+                &config.source_language,
+                target,
+                &segments,
+                cancel,
+            )
+            .await?;
+            let path = translated_output_path(&output_path, target);
+            write_subtitle_file(&path, output_format, &subtitles.render(&translated, target))
+                .await?;
+            Some(path_to_string(&path))
+        }
+        None => None,
+    };
+
+    if let Err(error) = jobs::delete_manifest(app, &manifest.id).await {
+        eprintln!("[processing] failed to remove finished job manifest: {error}");
+    }
+
+    emit_progress(
+        events,
+        ProcessingStage::Complete,
+        total_chunks,
+        total_chunks,
+        100.0,
+        "Subtitles ready".to_string(),
+    );
+
+    Ok(ProcessingResult {
+        output_path: path_to_string(&output_path),
+        output_language: output_language.to_string(),
+        translated_output_path,
+        segment_count: segments.len(),
+        chunk_count: total_chunks,
+    })
+}
+
+async fn plan_chunks_for_mode(
+    reporter: &dyn ProgressReporter,
+    sidecars: &Sidecars,
+    settings: &AppSettings,
+    video_info: &VideoInfo,
+    audio_stream: Option<u32>,
+    chunk_minutes: u32,
+    cancel: &CancellationToken,
+) -> Result<Vec<ChunkInfo>, String> {
+    let duration_seconds = video_info.duration_seconds;
+    let chunks = calculate_chunks_for_settings(settings, duration_seconds, chunk_minutes)?;
+    if settings.chunking_mode != ChunkingMode::Silence || chunks.len() < 2 {
+        return Ok(chunks);
+    }
+
+    let total_chunks = chunks.len() as u32;
+    emit_progress(
+        reporter,
+        ProcessingStage::Analyzing,
+        0,
+        total_chunks,
+        0.0,
+        "Detecting silences".to_string(),
+    );
+    let silences = ffmpeg::detect_silences(
+        sidecars,
+        AudioInput {
+            path: &video_info.path,
+            stream: audio_stream,
+        },
+        duration_seconds,
+        cancel,
+        |progress| {
+            emit_progress(
+                reporter,
+                ProcessingStage::Analyzing,
+                0,
+                total_chunks,
+                0.0,
+                format!(
+                    "Detecting silences ({}%)",
+                    (progress.fraction * 100.0).round()
+                ),
+            )
+        },
+    )
+    .await;
+
+    match silences {
+        Ok(silences) => calculate_chunks_with_silences(
+            duration_seconds,
+            chunk_minutes,
+            &silences,
+            f64::from(settings.silence_window_seconds),
+        ),
+        Err(error) => {
+            ensure_not_cancelled(cancel)?;
+            eprintln!("[processing] silence detection failed, using fixed chunks: {error}");
+            Ok(chunks)
+        }
+    }
+}
+
+pub(crate) async fn plan_chunks(
+    reporter: &dyn ProgressReporter,
+    sidecars: &Sidecars,
+    settings: &AppSettings,
+    video_info: &VideoInfo,
+    audio_stream: Option<u32>,
+    cancel: &CancellationToken,
+) -> Result<Vec<ChunkInfo>, String> {
+    let max_upload_bytes = providers::capabilities_for(settings).max_upload_bytes;
+    let chunk_minutes = planned_chunk_minutes(settings, settings.chunk_duration_minutes);
+    if let Some(notice) = shortened_chunks_notice(settings.chunk_duration_minutes, chunk_minutes) {
+        eprintln!("[processing] {notice}");
+    }
+
+    let chunks = plan_chunks_for_mode(
+        reporter,
+        sidecars,
+        settings,
+        video_info,
+        audio_stream,
+        chunk_minutes,
+        cancel,
+    )
+    .await?;
+    ensure_chunks_fit_upload_limit(&chunks, max_upload_bytes)?;
+
+    Ok(chunks)
+}
+
+#[cfg(feature = "gui")]
+pub(crate) async fn run_pipeline(
+    events: &JobEvents<'_>,
+    job_id: String,
+    config: &ProcessingConfig,
+    cancel: &CancellationToken,
+) -> Result<ProcessingResult, String> {
+    let app = events.app;
+    let settings = get_settings(app.clone()).await?;
+    settings.validate()?;
+    providers::ensure_credentials(&settings)?;
+    if chat_translation_target(config, &settings).is_some() {
+        translation::ensure_credentials(&settings)?;
+    }
+
+    let sidecars = Sidecars::from_app(app);
+    let video_info = probe_video(&sidecars, config.video_path.clone(), Some(cancel)).await?;
+
+    let mut config = config.clone();
+    let audio_stream = resolve_audio_stream(
+        &video_info.audio_streams,
+        config.audio_stream_index,
+        &config.source_language,
+    )?;
+    config.audio_stream_index = Some(audio_stream);
+    let chunks = plan_chunks(
+        events,
+        &sidecars,
+        &settings,
+        &video_info,
+        Some(audio_stream),
+        cancel,
+    )
+    .await?;
+
+    let output_format = *config
+        .output_format
+        .get_or_insert(settings.default_output_format);
+    let output_folder = config
+        .output_folder
+        .as_deref()
+        .or(settings.default_output_folder.as_deref());
+    let output_path =
+        resolve_output_path(Path::new(&video_info.path), output_folder, output_format)?;
+
+    let mut manifest = JobManifest::new(
+        job_id,
+        video_info.path.clone(),
+        path_to_string(&output_path),
+        config,
+        &settings,
+        chunks,
+    );
+    jobs::save_manifest(app, &mut manifest)
+        .await
+        .map_err(|error| format!("Failed to save job manifest: {error}"))?;
+
+    execute_job(events, manifest, &settings, cancel).await
+}
+
+#[cfg(feature = "gui")]
+pub(crate) async fn resume_pipeline(
+    events: &JobEvents<'_>,
+    job_id: &str,
+    cancel: &CancellationToken,
+) -> Result<ProcessingResult, String> {
+    let app = events.app;
+    let manifest = jobs::load_manifest(app, job_id)
+        .await
+        .map_err(|error| error.to_string())?;
+
+    if !Path::new(&manifest.input_path).is_file() {
+        return Err(format!(
+            "The original video is no longer available: {}",
+            manifest.input_path
+        ));
+    }
+
+    let current = get_settings(app.clone()).await?;
+    let mut settings = manifest.settings.clone();
+    settings.api_key = current.api_key;
+    settings.translation_api_key = current.translation_api_key;
+    providers::ensure_credentials(&settings)?;
+    if chat_translation_target(&manifest.config, &settings).is_some() {
+        translation::ensure_credentials(&settings)?;
+    }
+
+    execute_job(events, manifest, &settings, cancel).await
+}
+
+#[cfg(feature = "gui")]
+fn finish_job(
+    app: &AppHandle,
+    cancel: &CancellationToken,
+    result: Result<ProcessingResult, String>,
+) -> Result<ProcessingResult, String> {
+    let emitted = match &result {
+        Ok(processing_result) => app.emit(COMPLETE_EVENT, processing_result.clone()),
+        Err(_) if cancel.is_cancelled() => {
+            emit_progress(
+                &JobEvents::direct(app),
+                ProcessingStage::Cancelled,
+                0,
+                0,
+                0.0,
+                "Cancelled by user".to_string(),
+            );
+            app.emit(CANCELLED_EVENT, CANCELLED_MESSAGE)
+        }
+        Err(error) => app.emit(ERROR_EVENT, error.clone()),
+    };
+
+    if let Err(error) = emitted {
+        eprintln!("[processing] failed to emit result event: {error}");
+    }
+
+    if cancel.is_cancelled() {
+        return Err(CANCELLED_MESSAGE.to_string());
+    }
+
+    result
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn process_video(
+    app: AppHandle,
+    state: State<'_, ProcessingJobState>,
+    config: ProcessingConfig,
+) -> Result<ProcessingResult, String> {
+    let cancel = state.start()?;
+    let result = run_pipeline(&JobEvents::direct(&app), new_job_id(), &config, &cancel).await;
+    state.finish();
+
+    finish_job(&app, &cancel, result)
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn resume_job(
+    app: AppHandle,
+    state: State<'_, ProcessingJobState>,
+    job_id: String,
+) -> Result<ProcessingResult, String> {
+    let cancel = state.start()?;
+    let result = resume_pipeline(&JobEvents::direct(&app), &job_id, &cancel).await;
+    state.finish();
+
+    finish_job(&app, &cancel, result)
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub fn cancel_processing(state: State<'_, ProcessingJobState>) -> bool {
+    state.cancel()
+}
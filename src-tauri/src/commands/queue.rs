@@ -0,0 +1,375 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::fs;
+use tokio::sync::MutexGuard;
+use tokio_util::sync::CancellationToken;
+
+use crate::commands::jobs;
+use crate::commands::processing::{resume_pipeline, run_pipeline, JobEvents};
+use crate::commands::settings::get_settings;
+use crate::commands::video::validate_video_path;
+use crate::commands::watch::archive_processed_file;
+use crate::models::queue::{
+    JobOverrides, JobQueue, JobQueueState, QueueJob, QueueJobStatus, QueueRuntime,
+    QUEUE_UPDATED_EVENT,
+};
+use crate::models::watch::WatchFolder;
+
+const QUEUE_FILE_NAME: &str = "queue.json";
+
+fn queue_file_path(app: &AppHandle) -> Result<PathBuf> {
+    let mut dir = app
+        .path()
+        .app_data_dir()
+        .context("failed to resolve app data directory")?;
+
+    dir.push(QUEUE_FILE_NAME);
+    Ok(dir)
+}
+
+async fn read_queue_from_disk(app: &AppHandle) -> Result<JobQueue> {
+    let file_path = queue_file_path(app)?;
+    if !fs::try_exists(&file_path).await.unwrap_or(false) {
+        return Ok(JobQueue::default());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .await
+        .with_context(|| format!("failed to read job queue {}", file_path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse job queue {}", file_path.display()))
+}
+
+async fn write_queue_to_disk(app: &AppHandle, queue: &JobQueue) -> Result<()> {
+    let file_path = queue_file_path(app)?;
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .context("failed to create app data directory")?;
+    }
+
+    let content = serde_json::to_string_pretty(queue).context("failed to serialize job queue")?;
+    let temp_path = file_path.with_extension("tmp");
+
+    fs::write(&temp_path, content)
+        .await
+        .with_context(|| format!("failed to write job queue {}", temp_path.display()))?;
+    fs::rename(&temp_path, &file_path)
+        .await
+        .with_context(|| format!("failed to replace job queue {}", file_path.display()))?;
+
+    Ok(())
+}
+
+async fn lock_runtime<'a>(
+    app: &AppHandle,
+    state: &'a JobQueueState,
+) -> Result<MutexGuard<'a, QueueRuntime>, String> {
+    let mut runtime = state.runtime.lock().await;
+    if !runtime.loaded {
+        let mut queue = read_queue_from_disk(app)
+            .await
+            .map_err(|error| error.to_string())?;
+        queue.recover_interrupted();
+
+        runtime.queue = queue;
+        runtime.loaded = true;
+    }
+
+    Ok(runtime)
+}
+
+async fn persist(app: &AppHandle, runtime: &QueueRuntime) -> Result<JobQueue, String> {
+    write_queue_to_disk(app, &runtime.queue)
+        .await
+        .map_err(|error| format!("Failed to save job queue: {error}"))?;
+
+    if let Err(error) = app.emit(QUEUE_UPDATED_EVENT, runtime.queue.clone()) {
+        eprintln!("[queue] failed to emit queue update: {error}");
+    }
+
+    Ok(runtime.queue.clone())
+}
+
+async fn run_queued_job(app: AppHandle, job: QueueJob, cancel: CancellationToken) {
+    let events = JobEvents::queued(&app, &job.id);
+    let result = if jobs::load_manifest(&app, &job.id).await.is_ok() {
+        resume_pipeline(&events, &job.id, &cancel).await
+    } else {
+        match get_settings(app.clone()).await {
+            Ok(settings) => {
+                let config = job.overrides.resolve(&job.video_path, &settings);
+                run_pipeline(&events, job.id.clone(), &config, &cancel).await
+            }
+            Err(error) => Err(error),
+        }
+    };
+
+    let state = app.state::<JobQueueState>();
+    let mut runtime = state.runtime.lock().await;
+    runtime.running.remove(&job.id);
+    let mut archive_from = None;
+
+    match runtime.queue.find_mut(&job.id) {
+        None => {
+            if let Err(error) = jobs::delete_manifest(&app, &job.id).await {
+                eprintln!("[queue] failed to remove manifest of removed job: {error}");
+            }
+        }
+        // Whoever interrupted the job already set its next status.
+        Some(_) if cancel.is_cancelled() => {}
+        Some(entry) => match result {
+            Ok(processing_result) => {
+                entry.status = QueueJobStatus::Completed;
+                entry.output_path = Some(processing_result.output_path);
+                entry.error = None;
+                archive_from = entry.watch_folder_id.clone();
+            }
+            Err(error) => {
+                entry.status = QueueJobStatus::Failed;
+                entry.error = Some(error);
+            }
+        },
+    }
+
+    if let Err(error) = persist(&app, &runtime).await {
+        eprintln!("[queue] {error}");
+    }
+    drop(runtime);
+    state.wake.notify_one();
+
+    if let Some(folder_id) = archive_from {
+        if let Err(error) = archive_processed_file(&app, &folder_id, &job.video_path).await {
+            eprintln!("[queue] failed to archive {}: {error}", job.video_path);
+        }
+    }
+}
+
+async fn start_ready_jobs(app: &AppHandle) -> Result<(), String> {
+    let limit = get_settings(app.clone()).await?.max_concurrent_jobs.max(1) as usize;
+    let state = app.state::<JobQueueState>();
+    let mut runtime = lock_runtime(app, &state).await?;
+    if runtime.queue.paused {
+        return Ok(());
+    }
+
+    let mut started = false;
+    while runtime.running.len() < limit {
+        let Some(job) = runtime.queue.next_ready(&runtime.running).cloned() else {
+            break;
+        };
+
+        let cancel = CancellationToken::new();
+        runtime.running.insert(job.id.clone(), cancel.clone());
+        if let Some(entry) = runtime.queue.find_mut(&job.id) {
+            entry.status = QueueJobStatus::Running;
+            entry.error = None;
+        }
+
+        eprintln!("[queue] starting {}", job.video_path);
+        tauri::async_runtime::spawn(run_queued_job(app.clone(), job, cancel));
+        started = true;
+    }
+
+    if started {
+        persist(app, &runtime).await?;
+    }
+
+    Ok(())
+}
+
+/// Starts queued jobs whenever a slot frees up or the queue changes. Runs for the lifetime of
+/// the app.
+pub async fn run_queue_worker(app: AppHandle) {
+    loop {
+        if let Err(error) = start_ready_jobs(&app).await {
+            eprintln!("[queue] failed to start queued jobs: {error}");
+        }
+
+        app.state::<JobQueueState>().wake.notified().await;
+    }
+}
+
+/// Queues a file picked up by a watch folder unless the queue already has a job for it.
+/// Returns whether a job was added.
+pub(crate) async fn enqueue_watched_file(
+    app: &AppHandle,
+    video_path: String,
+    folder: &WatchFolder,
+) -> Result<bool, String> {
+    let state = app.state::<JobQueueState>();
+    let mut runtime = lock_runtime(app, &state).await?;
+    if runtime.queue.contains_video(&video_path) {
+        return Ok(false);
+    }
+
+    runtime
+        .queue
+        .add(video_path, folder.overrides.clone())
+        .watch_folder_id = Some(folder.id.clone());
+    persist(app, &runtime).await?;
+    drop(runtime);
+
+    state.wake.notify_one();
+    Ok(true)
+}
+
+pub(crate) async fn queued_job_ids(app: &AppHandle) -> Vec<String> {
+    let state = app.state::<JobQueueState>();
+    let ids = match lock_runtime(app, &state).await {
+        Ok(runtime) => runtime
+            .queue
+            .jobs
+            .iter()
+            .map(|job| job.id.clone())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    ids
+}
+
+fn interrupt(runtime: &mut QueueRuntime, job_id: &str, next_status: QueueJobStatus) {
+    if let Some(cancel) = runtime.running.get(job_id) {
+        cancel.cancel();
+    }
+    if let Some(entry) = runtime.queue.find_mut(job_id) {
+        entry.status = next_status;
+    }
+}
+
+#[tauri::command]
+pub async fn get_queue(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+) -> Result<JobQueue, String> {
+    let runtime = lock_runtime(&app, &state).await?;
+    Ok(runtime.queue.clone())
+}
+
+#[tauri::command]
+pub async fn add_to_queue(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    video_paths: Vec<String>,
+    overrides: JobOverrides,
+) -> Result<JobQueue, String> {
+    for path in &video_paths {
+        validate_video_path(Path::new(path))?;
+    }
+
+    let mut runtime = lock_runtime(&app, &state).await?;
+    for path in video_paths {
+        runtime.queue.add(path, overrides.clone());
+    }
+    let queue = persist(&app, &runtime).await?;
+    drop(runtime);
+
+    state.wake.notify_one();
+    Ok(queue)
+}
+
+#[tauri::command]
+pub async fn remove_from_queue(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    job_id: String,
+) -> Result<JobQueue, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    if runtime.queue.remove(&job_id).is_none() {
+        return Err(format!("Job {job_id} is not in the queue."));
+    }
+
+    // A running job cleans up its own manifest once it notices the cancellation.
+    match runtime.running.get(&job_id) {
+        Some(cancel) => cancel.cancel(),
+        None => jobs::delete_manifest(&app, &job_id)
+            .await
+            .map_err(|error| error.to_string())?,
+    }
+
+    persist(&app, &runtime).await
+}
+
+#[tauri::command]
+pub async fn reorder_queue(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    job_ids: Vec<String>,
+) -> Result<JobQueue, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    runtime.queue.reorder(&job_ids)?;
+
+    persist(&app, &runtime).await
+}
+
+#[tauri::command]
+pub async fn set_queue_paused(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    paused: bool,
+) -> Result<JobQueue, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    runtime.queue.paused = paused;
+
+    // Interrupted jobs go back to the queue and resume from their manifest when unpaused.
+    if paused {
+        let running: Vec<String> = runtime.running.keys().cloned().collect();
+        for job_id in running {
+            interrupt(&mut runtime, &job_id, QueueJobStatus::Queued);
+        }
+    }
+
+    let queue = persist(&app, &runtime).await?;
+    drop(runtime);
+
+    state.wake.notify_one();
+    Ok(queue)
+}
+
+#[tauri::command]
+pub async fn pause_queue_job(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    job_id: String,
+) -> Result<JobQueue, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    match runtime.queue.find_mut(&job_id).map(|job| job.status) {
+        Some(QueueJobStatus::Queued | QueueJobStatus::Running) => {
+            interrupt(&mut runtime, &job_id, QueueJobStatus::Paused);
+        }
+        Some(_) => return Err("Only queued or running jobs can be paused.".to_string()),
+        None => return Err(format!("Job {job_id} is not in the queue.")),
+    }
+
+    persist(&app, &runtime).await
+}
+
+#[tauri::command]
+pub async fn resume_queue_job(
+    app: AppHandle,
+    state: State<'_, JobQueueState>,
+    job_id: String,
+) -> Result<JobQueue, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    let job = runtime
+        .queue
+        .find_mut(&job_id)
+        .ok_or_else(|| format!("Job {job_id} is not in the queue."))?;
+
+    match job.status {
+        QueueJobStatus::Paused | QueueJobStatus::Failed => {
+            job.status = QueueJobStatus::Queued;
+            job.error = None;
+        }
+        _ => return Err("Only paused or failed jobs can be resumed.".to_string()),
+    }
+
+    let queue = persist(&app, &runtime).await?;
+    drop(runtime);
+
+    state.wake.notify_one();
+    Ok(queue)
+}
@@ -2,11 +2,15 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use tauri::{AppHandle, Manager};
+use tauri_plugin_dialog::DialogExt;
 use tokio::fs;
 
+use crate::commands::video::path_to_string;
 use crate::models::settings::AppSettings;
+use crate::providers::{capabilities_for, ProviderCapabilities};
 
 const SETTINGS_FILE_NAME: &str = "settings.json";
+const MODEL_EXTENSIONS: &[&str] = &["bin"];
 
 fn settings_file_path(app: &AppHandle) -> Result<PathBuf> {
     let mut dir = app
@@ -95,3 +99,28 @@ pub async fn save_settings(
 
     Ok(response)
 }
+
+#[tauri::command]
+pub fn get_provider_capabilities(settings: AppSettings) -> ProviderCapabilities {
+    capabilities_for(&settings)
+}
+
+#[tauri::command]
+pub fn select_model_file(app: AppHandle) -> Result<Option<String>, String> {
+    let selected = app
+        .dialog()
+        .file()
+        .set_title("Select whisper.cpp model")
+        .add_filter("GGML models", MODEL_EXTENSIONS)
+        .blocking_pick_file();
+
+    let Some(selected) = selected else {
+        return Ok(None);
+    };
+
+    let path = selected
+        .into_path()
+        .map_err(|error| format!("Failed to resolve selected file path: {error}"))?;
+
+    Ok(Some(path_to_string(&path)))
+}
@@ -0,0 +1,682 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "gui")]
+use tauri::{AppHandle, State};
+#[cfg(feature = "gui")]
+use tauri_plugin_dialog::DialogExt;
+use tokio_util::sync::CancellationToken;
+
+#[cfg(feature = "gui")]
+use crate::commands::processing::JobEvents;
+use crate::commands::processing::{
+    emit_progress, ensure_not_cancelled, resolve_output_folder, ProgressReporter,
+};
+#[cfg(feature = "gui")]
+use crate::commands::video::{path_to_string, probe_video};
+use crate::commands::video::{SubtitleStreamInfo, VideoInfo};
+#[cfg(feature = "gui")]
+use crate::models::job::new_job_id;
+use crate::models::language::{iso_639_2_code, language_name, supported_languages};
+#[cfg(feature = "gui")]
+use crate::models::processing::ProcessingJobState;
+use crate::models::processing::ProcessingStage;
+use crate::models::settings::SubtitleFormat;
+#[cfg(feature = "gui")]
+use crate::models::style::SubtitleStyle;
+#[cfg(feature = "gui")]
+use crate::utils::ffmpeg::BurnIn;
+use crate::utils::ffmpeg::{self, Sidecars, SubtitleMux, SubtitleTrackInput};
+#[cfg(feature = "gui")]
+use crate::utils::temp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MuxContainer {
+    Mkv,
+    Mp4,
+}
+
+impl MuxContainer {
+    /// MP4-family inputs stay MP4; everything else goes into Matroska, which takes any codec.
+    fn for_video(video_path: &Path) -> Self {
+        let extension = video_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("mp4" | "m4v" | "mov") => Self::Mp4,
+            _ => Self::Mkv,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Mkv => "mkv",
+            Self::Mp4 => "mp4",
+        }
+    }
+
+    /// MP4 only carries `mov_text`; Matroska keeps the subtitle file's own format.
+    fn subtitle_codec(self, subtitle_path: &Path) -> &'static str {
+        let extension = subtitle_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        match (self, extension.as_deref()) {
+            (Self::Mp4, _) => "mov_text",
+            (Self::Mkv, Some("ass" | "ssa")) => "ass",
+            (Self::Mkv, Some("vtt")) => "webvtt",
+            (Self::Mkv, _) => "srt",
+        }
+    }
+}
+
+/// A subtitle file to mux into the video. `language` is an app language code such as `de`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MuxTrack {
+    pub path: String,
+    pub language: Option<String>,
+}
+
+/// `<stem>.track<index>.<language>.<ext>`, or `<stem>.track<index>.<ext>` for untagged
+/// tracks. The stream index keeps extracted tracks apart from each other and from the
+/// transcripts the app writes as `<stem>.<ext>` and `<stem>.<language>.<ext>`. An existing
+/// file is never overwritten.
+#[cfg_attr(not(feature = "gui"), allow(dead_code))]
+fn extracted_subtitle_path(
+    folder: &Path,
+    video_path: &Path,
+    stream_index: u32,
+    language: Option<&str>,
+    format: SubtitleFormat,
+) -> Result<PathBuf, String> {
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| "Unable to determine video file name.".to_string())?;
+    let extension = format.extension();
+
+    let path = match language {
+        Some(language) => folder.join(format!("{stem}.track{stream_index}.{language}.{extension}")),
+        None => folder.join(format!("{stem}.track{stream_index}.{extension}")),
+    };
+    if path.exists() {
+        return Err(format!(
+            "{} already exists. Move or rename it to extract this track again.",
+            path.display()
+        ));
+    }
+
+    Ok(path)
+}
+
+#[cfg(feature = "gui")]
+pub(crate) async fn extract_embedded_subtitle_stream(
+    sidecars: &Sidecars,
+    video_info: &VideoInfo,
+    stream_index: u32,
+    format: SubtitleFormat,
+    output_folder: Option<&str>,
+    cancel: &CancellationToken,
+) -> Result<PathBuf, String> {
+    let stream = video_info
+        .subtitle_streams
+        .iter()
+        .find(|stream| stream.index == stream_index)
+        .ok_or_else(|| format!("The video has no subtitle stream with index {stream_index}."))?;
+    if !stream.is_text {
+        return Err(format!(
+            "Subtitle stream {stream_index} ({}) is image-based and cannot be converted to text.",
+            stream.codec_name
+        ));
+    }
+
+    let video_path = Path::new(&video_info.path);
+    let folder = resolve_output_folder(video_path, output_folder)?;
+    let output_path = extracted_subtitle_path(
+        &folder,
+        video_path,
+        stream_index,
+        stream.language.as_deref(),
+        format,
+    )?;
+
+    let extracted = ffmpeg::extract_subtitle_stream(
+        sidecars,
+        &video_info.path,
+        stream_index,
+        format.encoder(),
+        &output_path,
+        cancel,
+    )
+    .await;
+    if let Err(error) = extracted {
+        ensure_not_cancelled(cancel)?;
+        return Err(format!(
+            "Failed to extract subtitle stream {stream_index}: {error}"
+        ));
+    }
+
+    Ok(output_path)
+}
+
+#[cfg(feature = "gui")]
+const SUBTITLE_EXTENSIONS: &[&str] = &["srt", "vtt", "ass", "ssa"];
+
+/// Reads the language from names like `talk.de.srt`, the pattern translated files use.
+#[cfg_attr(not(feature = "gui"), allow(dead_code))]
+fn language_from_file_name(path: &Path) -> Option<String> {
+    let code = Path::new(path.file_stem()?).extension()?.to_str()?;
+    supported_languages()
+        .into_iter()
+        .find(|language| language.code == code)
+        .map(|language| language.code.to_string())
+}
+
+fn muxed_output_path(
+    folder: &Path,
+    video_path: &Path,
+    container: MuxContainer,
+) -> Result<PathBuf, String> {
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| "Unable to determine video file name.".to_string())?;
+
+    Ok(folder.join(format!("{stem}.subtitled.{}", container.extension())))
+}
+
+/// The source streams a muxed copy keeps, as `-map` selectors, and the encoder for each kept
+/// subtitle stream. Video, audio and subtitle streams are mapped one by one so timecode and
+/// data tracks, which neither muxer accepts, are left behind. MKV also keeps attachments
+/// such as fonts and converts `mov_text` to SubRip, which Matroska cannot hold; MP4 keeps
+/// only text subtitles, all converted to `mov_text`.
+fn kept_streams(
+    container: MuxContainer,
+    subtitle_streams: &[SubtitleStreamInfo],
+) -> (Vec<String>, Vec<Option<&'static str>>) {
+    let mut selectors = vec!["0:v?".to_string(), "0:a?".to_string()];
+    let mut subtitle_codecs = Vec::new();
+    for stream in subtitle_streams {
+        let codec = match container {
+            MuxContainer::Mkv if stream.codec_name == "mov_text" => Some("srt"),
+            MuxContainer::Mkv => None,
+            MuxContainer::Mp4 if stream.is_text => Some("mov_text"),
+            MuxContainer::Mp4 => continue,
+        };
+        selectors.push(format!("0:{}", stream.index));
+        subtitle_codecs.push(codec);
+    }
+    if container == MuxContainer::Mkv {
+        selectors.push("0:t?".to_string());
+    }
+
+    (selectors, subtitle_codecs)
+}
+
+/// Writes a copy of the video with `tracks` added as language-tagged subtitle streams. The
+/// original video and audio streams are copied without re-encoding; see [`kept_streams`]
+/// for what else is carried over.
+pub(crate) async fn mux_subtitle_tracks(
+    reporter: &dyn ProgressReporter,
+    sidecars: &Sidecars,
+    video_info: &VideoInfo,
+    tracks: &[MuxTrack],
+    container: Option<MuxContainer>,
+    output_folder: Option<&str>,
+    cancel: &CancellationToken,
+) -> Result<PathBuf, String> {
+    if tracks.is_empty() {
+        return Err("Choose at least one subtitle file to add.".to_string());
+    }
+    if let Some(missing) = tracks
+        .iter()
+        .find(|track| !Path::new(&track.path).is_file())
+    {
+        return Err(format!("Subtitle file not found: {}", missing.path));
+    }
+
+    let video_path = Path::new(&video_info.path);
+    let container = container.unwrap_or_else(|| MuxContainer::for_video(video_path));
+    let folder = resolve_output_folder(video_path, output_folder)?;
+    let output_path = muxed_output_path(&folder, video_path, container)?;
+    if output_path == video_path {
+        return Err("The subtitled video would overwrite the original.".to_string());
+    }
+
+    let (keep_streams, existing_subtitle_codecs) =
+        kept_streams(container, &video_info.subtitle_streams);
+
+    let titles: Vec<Option<String>> = tracks
+        .iter()
+        .map(|track| track.language.as_deref().map(language_name))
+        .collect();
+    let inputs: Vec<SubtitleTrackInput> = tracks
+        .iter()
+        .zip(&titles)
+        .map(|(track, title)| {
+            let language = track.language.as_deref();
+            SubtitleTrackInput {
+                path: &track.path,
+                codec: container.subtitle_codec(Path::new(&track.path)),
+                language: language.map(|code| iso_639_2_code(code).unwrap_or(code)),
+                title: title.as_deref(),
+            }
+        })
+        .collect();
+    let mux = SubtitleMux {
+        input: &video_info.path,
+        keep_streams: &keep_streams,
+        existing_subtitle_codecs: &existing_subtitle_codecs,
+        tracks: &inputs,
+        output: &output_path,
+    };
+
+    emit_progress(
+        reporter,
+        ProcessingStage::Muxing,
+        0,
+        0,
+        0.0,
+        "Adding subtitle tracks".to_string(),
+    );
+    let muxed = ffmpeg::mux_subtitles(
+        sidecars,
+        &mux,
+        video_info.duration_seconds,
+        cancel,
+        |progress| {
+            emit_progress(
+                reporter,
+                ProcessingStage::Muxing,
+                0,
+                0,
+                progress.fraction * 100.0,
+                format!(
+                    "Adding subtitle tracks ({}%)",
+                    (progress.fraction * 100.0).round()
+                ),
+            )
+        },
+    )
+    .await;
+    if let Err(error) = muxed {
+        let _ = tokio::fs::remove_file(&output_path).await;
+        ensure_not_cancelled(cancel)?;
+        return Err(format!("Failed to add subtitle tracks: {error}"));
+    }
+
+    Ok(output_path)
+}
+
+#[cfg(feature = "gui")]
+fn burned_output_path(folder: &Path, video_path: &Path) -> Result<PathBuf, String> {
+    let stem = video_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| "Unable to determine video file name.".to_string())?;
+
+    Ok(folder.join(format!("{stem}.burned.mp4")))
+}
+
+/// Re-encodes the video with the subtitles drawn onto every frame. Audio is re-encoded
+/// to AAC so the result always fits in MP4.
+#[cfg(feature = "gui")]
+pub(crate) async fn burn_in_subtitle_file(
+    reporter: &dyn ProgressReporter,
+    sidecars: &Sidecars,
+    video_info: &VideoInfo,
+    subtitle_path: &str,
+    style: &SubtitleStyle,
+    output_folder: Option<&str>,
+    cancel: &CancellationToken,
+) -> Result<PathBuf, String> {
+    style.validate()?;
+    if !Path::new(subtitle_path).is_file() {
+        return Err(format!("Subtitle file not found: {subtitle_path}"));
+    }
+
+    let video_path = Path::new(&video_info.path);
+    let folder = resolve_output_folder(video_path, output_folder)?;
+    let output_path = burned_output_path(&folder, video_path)?;
+    if output_path == video_path {
+        return Err("The rendered video would overwrite the original.".to_string());
+    }
+
+    let force_style = style.force_style();
+    let burn_in = BurnIn {
+        input: &video_info.path,
+        subtitle_path,
+        force_style: &force_style,
+    };
+
+    emit_progress(
+        reporter,
+        ProcessingStage::Rendering,
+        0,
+        0,
+        0.0,
+        "Rendering subtitles into the video".to_string(),
+    );
+    let rendered = ffmpeg::burn_in_subtitles(
+        sidecars,
+        &burn_in,
+        &output_path,
+        video_info.duration_seconds,
+        cancel,
+        |progress| {
+            let speed = progress
+                .speed
+                .map(|speed| format!(", {speed:.1}x"))
+                .unwrap_or_default();
+            emit_progress(
+                reporter,
+                ProcessingStage::Rendering,
+                0,
+                0,
+                progress.fraction * 100.0,
+                format!(
+                    "Rendering subtitles into the video ({}%{speed})",
+                    (progress.fraction * 100.0).round()
+                ),
+            )
+        },
+    )
+    .await;
+    if let Err(error) = rendered {
+        let _ = tokio::fs::remove_file(&output_path).await;
+        ensure_not_cancelled(cancel)?;
+        return Err(format!(
+            "Failed to render subtitles into the video: {error}"
+        ));
+    }
+
+    Ok(output_path)
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn extract_embedded_subtitles(
+    app: AppHandle,
+    state: State<'_, ProcessingJobState>,
+    video_path: String,
+    stream_index: u32,
+    format: Option<SubtitleFormat>,
+    output_folder: Option<String>,
+) -> Result<String, String> {
+    let cancel = state.start()?;
+    let sidecars = Sidecars::from_app(&app);
+    let result = async {
+        let video_info = probe_video(&sidecars, video_path, Some(&cancel)).await?;
+        extract_embedded_subtitle_stream(
+            &sidecars,
+            &video_info,
+            stream_index,
+            format.unwrap_or_default(),
+            output_folder.as_deref(),
+            &cancel,
+        )
+        .await
+    }
+    .await;
+    state.finish();
+
+    result.map(|path| path_to_string(&path))
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub fn select_subtitle_files(app: AppHandle) -> Result<Vec<MuxTrack>, String> {
+    let Some(selected) = app
+        .dialog()
+        .file()
+        .set_title("Select subtitle files")
+        .add_filter("Subtitle files", SUBTITLE_EXTENSIONS)
+        .blocking_pick_files()
+    else {
+        return Ok(Vec::new());
+    };
+
+    selected
+        .into_iter()
+        .map(|file| {
+            let path = file
+                .into_path()
+                .map_err(|error| format!("Failed to resolve selected file path: {error}"))?;
+            Ok(MuxTrack {
+                language: language_from_file_name(&path),
+                path: path_to_string(&path),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn mux_subtitles(
+    app: AppHandle,
+    state: State<'_, ProcessingJobState>,
+    video_path: String,
+    tracks: Vec<MuxTrack>,
+    container: Option<MuxContainer>,
+    output_folder: Option<String>,
+) -> Result<String, String> {
+    let cancel = state.start()?;
+    let sidecars = Sidecars::from_app(&app);
+    let result = async {
+        let video_info = probe_video(&sidecars, video_path, Some(&cancel)).await?;
+        mux_subtitle_tracks(
+            &JobEvents::direct(&app),
+            &sidecars,
+            &video_info,
+            &tracks,
+            container,
+            output_folder.as_deref(),
+            &cancel,
+        )
+        .await
+    }
+    .await;
+    state.finish();
+
+    result.map(|path| path_to_string(&path))
+}
+
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn burn_in_subtitles(
+    app: AppHandle,
+    state: State<'_, ProcessingJobState>,
+    video_path: String,
+    subtitle_path: String,
+    style: SubtitleStyle,
+    output_folder: Option<String>,
+) -> Result<String, String> {
+    let cancel = state.start()?;
+    let sidecars = Sidecars::from_app(&app);
+    let result = async {
+        let video_info = probe_video(&sidecars, video_path, Some(&cancel)).await?;
+        burn_in_subtitle_file(
+            &JobEvents::direct(&app),
+            &sidecars,
+            &video_info,
+            &subtitle_path,
+            &style,
+            output_folder.as_deref(),
+            &cancel,
+        )
+        .await
+    }
+    .await;
+    state.finish();
+
+    result.map(|path| path_to_string(&path))
+}
+
+/// Renders a single frame with the subtitles burned in so a style can be checked before
+/// the full encode. Returns the JPEG bytes.
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn render_burn_in_preview(
+    app: AppHandle,
+    video_path: String,
+    subtitle_path: String,
+    style: SubtitleStyle,
+    timestamp_seconds: f64,
+) -> Result<Vec<u8>, String> {
+    style.validate()?;
+    if !Path::new(&subtitle_path).is_file() {
+        return Err(format!("Subtitle file not found: {subtitle_path}"));
+    }
+    if !timestamp_seconds.is_finite() || timestamp_seconds < 0.0 {
+        return Err("Preview timestamp must be zero or positive.".to_string());
+    }
+
+    let force_style = style.force_style();
+    let burn_in = BurnIn {
+        input: &video_path,
+        subtitle_path: &subtitle_path,
+        force_style: &force_style,
+    };
+    let preview_dir = temp::create_job_dir(&temp::jobs_root(&app)?, &new_job_id()).await?;
+    let preview_path = preview_dir.join("preview.jpg");
+
+    let frame = match ffmpeg::render_preview_frame(
+        &Sidecars::from_app(&app),
+        &burn_in,
+        timestamp_seconds,
+        &preview_path,
+    )
+    .await
+    {
+        Ok(_) => tokio::fs::read(&preview_path)
+            .await
+            .map_err(|error| format!("Failed to read preview frame: {error}")),
+        Err(error) => Err(format!("Failed to render preview frame: {error}")),
+    };
+    temp::cleanup_job_dir(&preview_dir).await;
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{extracted_subtitle_path, kept_streams, language_from_file_name, MuxContainer};
+    use crate::commands::video::SubtitleStreamInfo;
+    use crate::models::settings::SubtitleFormat;
+    use crate::utils::ffmpeg::{mux_subtitles_args, SubtitleMux, SubtitleTrackInput};
+
+    #[test]
+    fn names_extracted_tracks_after_their_index_and_language() {
+        let folder = Path::new("/out");
+        let video = Path::new("/videos/talk.mkv");
+
+        let tagged = extracted_subtitle_path(folder, video, 2, Some("ger"), SubtitleFormat::Vtt);
+        assert_eq!(
+            tagged,
+            Ok(Path::new("/out/talk.track2.ger.vtt").to_path_buf())
+        );
+
+        let untagged = extracted_subtitle_path(folder, video, 3, None, SubtitleFormat::Srt);
+        assert_eq!(
+            untagged,
+            Ok(Path::new("/out/talk.track3.srt").to_path_buf())
+        );
+    }
+
+    #[test]
+    fn keeps_untagged_tracks_away_from_transcripts_and_existing_files() {
+        let folder = std::env::temp_dir().join("extracted-subtitle-path-test");
+        std::fs::create_dir_all(&folder).expect("create folder");
+        let video = Path::new("/videos/talk.mkv");
+        std::fs::write(folder.join("talk.srt"), "transcript").expect("write transcript");
+        let _ = std::fs::remove_file(folder.join("talk.track4.srt"));
+
+        let path = extracted_subtitle_path(&folder, video, 4, None, SubtitleFormat::Srt);
+        assert_eq!(path, Ok(folder.join("talk.track4.srt")));
+
+        std::fs::write(folder.join("talk.track4.srt"), "extracted").expect("write track");
+        let existing = extracted_subtitle_path(&folder, video, 4, None, SubtitleFormat::Srt);
+        assert!(existing.unwrap_err().contains("already exists"));
+
+        std::fs::remove_dir_all(&folder).expect("remove folder");
+    }
+
+    #[test]
+    fn picks_container_and_subtitle_codecs() {
+        assert_eq!(
+            MuxContainer::for_video(Path::new("/v/talk.MOV")),
+            MuxContainer::Mp4
+        );
+        assert_eq!(
+            MuxContainer::for_video(Path::new("/v/talk.avi")),
+            MuxContainer::Mkv
+        );
+
+        let srt = Path::new("/out/talk.de.srt");
+        assert_eq!(MuxContainer::Mp4.subtitle_codec(srt), "mov_text");
+        assert_eq!(MuxContainer::Mkv.subtitle_codec(srt), "srt");
+        assert_eq!(
+            MuxContainer::Mkv.subtitle_codec(Path::new("/out/talk.ass")),
+            "ass"
+        );
+    }
+
+    #[test]
+    fn muxes_mp4_sources_into_mkv_without_data_streams_or_mov_text() {
+        // Stream 0 is video, 1 audio, 2 a mov_text subtitle and 3 a tmcd data track, which
+        // ffprobe does not report as a subtitle stream.
+        let subtitle_streams = [SubtitleStreamInfo {
+            index: 2,
+            codec_name: "mov_text".to_string(),
+            language: Some("eng".to_string()),
+            title: None,
+            is_default: true,
+            is_forced: false,
+            is_text: true,
+        }];
+        let (keep, codecs) = kept_streams(MuxContainer::Mkv, &subtitle_streams);
+        let tracks = [SubtitleTrackInput {
+            path: "/out/talk.de.srt",
+            codec: "srt",
+            language: Some("ger"),
+            title: None,
+        }];
+        let args = mux_subtitles_args(&SubtitleMux {
+            input: "/videos/talk.mp4",
+            keep_streams: &keep,
+            existing_subtitle_codecs: &codecs,
+            tracks: &tracks,
+            output: Path::new("/out/talk.subtitled.mkv"),
+        });
+
+        let maps: Vec<&str> = args
+            .windows(2)
+            .filter(|pair| pair[0] == "-map")
+            .map(|pair| pair[1].as_str())
+            .collect();
+        assert_eq!(maps, ["0:v?", "0:a?", "0:2", "0:t?", "1:0"]);
+        assert!(args.windows(2).any(|pair| pair == ["-c:s:0", "srt"]));
+        assert!(args.windows(2).any(|pair| pair == ["-c:s:1", "srt"]));
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["-metadata:s:s:1", "language=ger"]));
+
+        let (keep, codecs) = kept_streams(MuxContainer::Mp4, &subtitle_streams);
+        assert_eq!(keep, ["0:v?", "0:a?", "0:2"]);
+        assert_eq!(codecs, [Some("mov_text")]);
+    }
+
+    #[test]
+    fn guesses_language_from_translated_file_names() {
+        let language = |path: &str| language_from_file_name(Path::new(path));
+
+        assert_eq!(language("/out/talk.de.srt").as_deref(), Some("de"));
+        assert_eq!(language("/out/talk.srt"), None);
+        assert_eq!(language("/out/talk.final.srt"), None);
+    }
+}
@@ -2,16 +2,28 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "gui")]
 use tauri::AppHandle;
+#[cfg(feature = "gui")]
 use tauri_plugin_dialog::DialogExt;
 use tokio::fs;
+use tokio_util::sync::CancellationToken;
 
-use crate::utils::ffmpeg::execute_ffprobe_command;
+#[cfg(feature = "gui")]
+use crate::commands::settings::get_settings;
+use crate::models::language::language_tag_matches;
+use crate::models::settings::{AppSettings, ChunkingMode};
+use crate::providers::capabilities_for;
+use crate::utils::ffmpeg::{
+    estimate_upload_bytes, execute_ffprobe_command, execute_ffprobe_command_with_cancel,
+    max_upload_seconds, Sidecars, SilenceInterval,
+};
 
 const VIDEO_EXTENSIONS: &[&str] = &["mp4", "avi", "mkv", "mov", "wmv"];
-const COST_RATE_PER_MINUTE_USD: f64 = 0.006;
 const COST_WARNING_THRESHOLD_USD: f64 = 5.0;
+const MIN_CHUNK_DURATION_MINUTES: u32 = 5;
 
+#[cfg(feature = "gui")]
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VideoFileInfo {
@@ -20,13 +32,44 @@ pub struct VideoFileInfo {
     pub size_bytes: u64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ChunkInfo {
     pub chunk_number: u32,
     pub start_time_seconds: f64,
     pub end_time_seconds: f64,
     pub duration_seconds: f64,
+    pub padded_start_seconds: f64,
+    pub padded_end_seconds: f64,
+    pub expected_size_bytes: u64,
+}
+
+impl ChunkInfo {
+    fn new(chunk_number: u32, start_time_seconds: f64, end_time_seconds: f64) -> Self {
+        Self {
+            chunk_number,
+            start_time_seconds,
+            end_time_seconds,
+            duration_seconds: end_time_seconds - start_time_seconds,
+            padded_start_seconds: start_time_seconds,
+            padded_end_seconds: end_time_seconds,
+            expected_size_bytes: estimate_upload_bytes(end_time_seconds - start_time_seconds),
+        }
+    }
+
+    pub fn padded_duration_seconds(&self) -> f64 {
+        self.padded_end_seconds - self.padded_start_seconds
+    }
+}
+
+#[cfg_attr(not(feature = "gui"), allow(dead_code))]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkPlan {
+    pub chunks: Vec<ChunkInfo>,
+    pub chunk_duration_minutes: u32,
+    /// Set when the chunks had to be shortened to fit the provider's upload limit.
+    pub notice: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -40,6 +83,54 @@ pub struct VideoInfo {
     pub codec_name: String,
     pub width: Option<u32>,
     pub height: Option<u32>,
+    pub bit_rate: Option<u64>,
+    pub start_time_seconds: f64,
+    pub video_bit_rate: Option<u64>,
+    pub frame_rate: Option<f64>,
+    pub variable_frame_rate: bool,
+    /// Clockwise rotation in degrees that players apply when displaying the video.
+    pub rotation: u32,
+    pub audio_streams: Vec<AudioStreamInfo>,
+    pub subtitle_streams: Vec<SubtitleStreamInfo>,
+    pub chapters: Vec<ChapterInfo>,
+}
+
+/// An audio track as reported by ffprobe. `index` is the stream's absolute index in the
+/// container, which is what ffmpeg's `-map 0:<index>` expects.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStreamInfo {
+    pub index: u32,
+    pub codec_name: String,
+    pub channels: Option<u32>,
+    pub channel_layout: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub bit_rate: Option<u64>,
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtitleStreamInfo {
+    pub index: u32,
+    pub codec_name: String,
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub is_default: bool,
+    pub is_forced: bool,
+    /// Text-based tracks can be converted to SRT/VTT; bitmap tracks (PGS, VobSub, DVB)
+    /// would need OCR.
+    pub is_text: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterInfo {
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+    pub title: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -57,23 +148,207 @@ pub struct CostEstimate {
 struct FFprobeOutput {
     format: Option<FFprobeFormat>,
     streams: Option<Vec<FFprobeStream>>,
+    #[serde(default)]
+    chapters: Vec<FFprobeChapter>,
 }
 
 #[derive(Debug, Deserialize)]
 struct FFprobeFormat {
     duration: Option<String>,
     format_name: Option<String>,
+    bit_rate: Option<String>,
+    start_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FFprobeChapter {
+    start_time: Option<String>,
+    end_time: Option<String>,
+    #[serde(default)]
+    tags: FFprobeTags,
 }
 
 #[derive(Debug, Deserialize)]
 struct FFprobeStream {
+    index: Option<u32>,
     codec_type: Option<String>,
     codec_name: Option<String>,
     width: Option<u32>,
     height: Option<u32>,
+    channels: Option<u32>,
+    channel_layout: Option<String>,
+    sample_rate: Option<String>,
+    bit_rate: Option<String>,
+    r_frame_rate: Option<String>,
+    avg_frame_rate: Option<String>,
+    #[serde(default)]
+    side_data_list: Vec<FFprobeSideData>,
+    #[serde(default)]
+    tags: FFprobeTags,
+    #[serde(default)]
+    disposition: FFprobeDisposition,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FFprobeTags {
+    language: Option<String>,
+    title: Option<String>,
+    rotate: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FFprobeDisposition {
+    #[serde(default)]
+    default: u8,
+    #[serde(default)]
+    forced: u8,
+}
+
+#[derive(Debug, Deserialize)]
+struct FFprobeSideData {
+    rotation: Option<f64>,
+}
+
+const TEXT_SUBTITLE_CODECS: &[&str] = &[
+    "subrip",
+    "srt",
+    "ass",
+    "ssa",
+    "webvtt",
+    "mov_text",
+    "text",
+    "microdvd",
+    "subviewer",
+    "subviewer1",
+    "sami",
+    "realtext",
+    "mpl2",
+    "pjs",
+    "vplayer",
+    "jacosub",
+    "stl",
+];
+
+/// ffprobe prints most numbers as strings and uses "N/A" for unknown values.
+fn parse_number<T: std::str::FromStr>(value: Option<&str>) -> Option<T> {
+    value.and_then(|value| value.trim().parse().ok())
+}
+
+fn parse_tag(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty() && value != "und")
+}
+
+/// Parses an ffprobe rate such as `30000/1001`. `0/0` means the rate is unknown.
+fn parse_frame_rate(value: Option<&str>) -> Option<f64> {
+    let value = value?.trim();
+    let rate = match value.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator: f64 = numerator.parse().ok()?;
+            let denominator: f64 = denominator.parse().ok()?;
+            numerator / denominator
+        }
+        None => value.parse().ok()?,
+    };
+
+    (rate.is_finite() && rate > 0.0).then_some(rate)
+}
+
+// `r_frame_rate` is the base rate every timestamp fits on; for constant frame rate video
+// it equals the average rate, while phone and screen recordings drift away from it.
+const VARIABLE_FRAME_RATE_TOLERANCE: f64 = 0.01;
+
+fn is_variable_frame_rate(base_rate: Option<f64>, average_rate: Option<f64>) -> bool {
+    match (base_rate, average_rate) {
+        (Some(base), Some(average)) => {
+            (base - average).abs() / base.max(average) > VARIABLE_FRAME_RATE_TOLERANCE
+        }
+        _ => false,
+    }
+}
+
+impl FFprobeStream {
+    /// The display matrix stores a counter-clockwise angle while the legacy `rotate` tag is
+    /// clockwise; both are normalized to clockwise degrees in `0..360`.
+    fn rotation(&self) -> u32 {
+        let degrees = self
+            .side_data_list
+            .iter()
+            .find_map(|side_data| side_data.rotation)
+            .map(|rotation| -rotation)
+            .or_else(|| parse_number(self.tags.rotate.as_deref()))
+            .unwrap_or(0.0);
+
+        (degrees.round() as i64).rem_euclid(360) as u32
+    }
+
+    fn audio_stream_info(self, position: usize) -> AudioStreamInfo {
+        AudioStreamInfo {
+            index: self.index.unwrap_or(position as u32),
+            codec_name: self.codec_name.unwrap_or_else(|| "unknown".to_string()),
+            channels: self.channels,
+            channel_layout: self.channel_layout.filter(|layout| !layout.is_empty()),
+            sample_rate: parse_number(self.sample_rate.as_deref()),
+            bit_rate: parse_number(self.bit_rate.as_deref()),
+            language: parse_tag(self.tags.language),
+            title: parse_tag(self.tags.title),
+            is_default: self.disposition.default != 0,
+        }
+    }
+
+    fn subtitle_stream_info(self, position: usize) -> SubtitleStreamInfo {
+        let codec_name = self.codec_name.unwrap_or_else(|| "unknown".to_string());
+
+        SubtitleStreamInfo {
+            index: self.index.unwrap_or(position as u32),
+            is_text: TEXT_SUBTITLE_CODECS.contains(&codec_name.as_str()),
+            codec_name,
+            language: parse_tag(self.tags.language),
+            title: parse_tag(self.tags.title),
+            is_default: self.disposition.default != 0,
+            is_forced: self.disposition.forced != 0,
+        }
+    }
 }
 
-fn path_to_string(path: &Path) -> String {
+/// Picks the audio stream to transcribe. An explicit index must name one of the file's
+/// audio streams; otherwise a track tagged with the source language wins, then the
+/// container's default track, then the first one.
+pub(crate) fn resolve_audio_stream(
+    audio_streams: &[AudioStreamInfo],
+    requested: Option<u32>,
+    source_language: &str,
+) -> Result<u32, String> {
+    if let Some(index) = requested {
+        return audio_streams
+            .iter()
+            .find(|stream| stream.index == index)
+            .map(|stream| stream.index)
+            .ok_or_else(|| format!("The video has no audio stream with index {index}."));
+    }
+
+    let matches_language = |stream: &&AudioStreamInfo| {
+        stream
+            .language
+            .as_deref()
+            .is_some_and(|tag| language_tag_matches(tag, source_language))
+    };
+    let language_matches: Vec<&AudioStreamInfo> =
+        audio_streams.iter().filter(matches_language).collect();
+
+    language_matches
+        .iter()
+        .find(|stream| stream.is_default)
+        .or_else(|| language_matches.first())
+        .copied()
+        .or_else(|| audio_streams.iter().find(|stream| stream.is_default))
+        .or_else(|| audio_streams.first())
+        .map(|stream| stream.index)
+        .ok_or_else(|| "The video has no audio streams to transcribe.".to_string())
+}
+
+pub(crate) fn path_to_string(path: &Path) -> String {
     let as_string = path.to_string_lossy().to_string();
     #[cfg(target_os = "windows")]
     {
@@ -96,7 +371,7 @@ fn has_supported_video_extension(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
-fn validate_video_path(path: &Path) -> Result<(), String> {
+pub(crate) fn validate_video_path(path: &Path) -> Result<(), String> {
     if !path.exists() {
         return Err(format!("Selected file does not exist: {}", path.display()));
     }
@@ -150,12 +425,7 @@ pub fn calculate_chunks_for_duration(
         let chunk_duration = remaining.min(chunk_duration_seconds);
         let end = (start + chunk_duration).min(duration_seconds);
 
-        chunks.push(ChunkInfo {
-            chunk_number,
-            start_time_seconds: start,
-            end_time_seconds: end,
-            duration_seconds: chunk_duration,
-        });
+        chunks.push(ChunkInfo::new(chunk_number, start, end));
 
         chunk_number += 1;
         start = end;
@@ -164,6 +434,162 @@ pub fn calculate_chunks_for_duration(
     Ok(chunks)
 }
 
+/// Largest chunk length (up to `requested_minutes`) whose audio still fits in a single
+/// upload once `padding_seconds` of extra audio is added around it.
+pub fn upload_safe_chunk_minutes(
+    requested_minutes: u32,
+    padding_seconds: f64,
+    max_upload_bytes: u64,
+) -> u32 {
+    let budget_seconds = max_upload_seconds(max_upload_bytes) - padding_seconds.max(0.0);
+    let max_minutes = (budget_seconds / 60.0).floor().max(0.0) as u32;
+
+    requested_minutes.min(max_minutes.max(MIN_CHUNK_DURATION_MINUTES))
+}
+
+pub fn ensure_chunks_fit_upload_limit(
+    chunks: &[ChunkInfo],
+    max_upload_bytes: u64,
+) -> Result<(), String> {
+    match chunks
+        .iter()
+        .find(|chunk| chunk.expected_size_bytes > max_upload_bytes)
+    {
+        Some(chunk) => Err(format!(
+            "Chunk {} would be about {:.1} MB, which exceeds the {} MB upload limit. Use shorter chunks.",
+            chunk.chunk_number,
+            chunk.expected_size_bytes as f64 / 1_048_576.0,
+            max_upload_bytes / 1_048_576
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Extra audio each chunk carries beyond its own span: the silence search window, or the
+/// overlap on both sides.
+pub fn chunk_padding_seconds(settings: &AppSettings) -> f64 {
+    match settings.chunking_mode {
+        ChunkingMode::Fixed => 0.0,
+        ChunkingMode::Silence => f64::from(settings.silence_window_seconds),
+        ChunkingMode::Overlap => f64::from(settings.chunk_overlap_seconds) * 2.0,
+    }
+}
+
+/// The chunk length actually used for `requested_minutes` with these settings.
+pub fn planned_chunk_minutes(settings: &AppSettings, requested_minutes: u32) -> u32 {
+    upload_safe_chunk_minutes(
+        requested_minutes,
+        chunk_padding_seconds(settings),
+        capabilities_for(settings).max_upload_bytes,
+    )
+}
+
+pub fn shortened_chunks_notice(requested_minutes: u32, chunk_minutes: u32) -> Option<String> {
+    (chunk_minutes < requested_minutes).then(|| {
+        format!(
+            "Chunks were shortened from {requested_minutes} to {chunk_minutes} minutes to stay under the provider's upload limit."
+        )
+    })
+}
+
+/// Chunks for the settings' chunking mode. Silence mode gets its nominal boundaries here;
+/// the cuts only move once silences have been detected.
+pub fn calculate_chunks_for_settings(
+    settings: &AppSettings,
+    duration_seconds: f64,
+    chunk_minutes: u32,
+) -> Result<Vec<ChunkInfo>, String> {
+    match settings.chunking_mode {
+        ChunkingMode::Fixed | ChunkingMode::Silence => {
+            calculate_chunks_for_duration(duration_seconds, chunk_minutes)
+        }
+        ChunkingMode::Overlap => calculate_chunks_with_overlap(
+            duration_seconds,
+            chunk_minutes,
+            f64::from(settings.chunk_overlap_seconds),
+        ),
+    }
+}
+
+#[cfg_attr(not(feature = "gui"), allow(dead_code))]
+pub fn chunk_plan_for(
+    settings: &AppSettings,
+    duration_seconds: f64,
+    requested_minutes: u32,
+) -> Result<ChunkPlan, String> {
+    let chunk_minutes = planned_chunk_minutes(settings, requested_minutes);
+
+    Ok(ChunkPlan {
+        chunks: calculate_chunks_for_settings(settings, duration_seconds, chunk_minutes)?,
+        chunk_duration_minutes: chunk_minutes,
+        notice: shortened_chunks_notice(requested_minutes, chunk_minutes),
+    })
+}
+
+fn nearest_silence_cut(
+    target: f64,
+    silences: &[SilenceInterval],
+    window_seconds: f64,
+) -> Option<f64> {
+    silences
+        .iter()
+        .map(|silence| {
+            if (silence.start_seconds..=silence.end_seconds).contains(&target) {
+                target
+            } else {
+                (silence.start_seconds + silence.end_seconds) / 2.0
+            }
+        })
+        .filter(|cut| (cut - target).abs() <= window_seconds)
+        .min_by(|a, b| (a - target).abs().total_cmp(&(b - target).abs()))
+}
+
+pub fn calculate_chunks_with_silences(
+    duration_seconds: f64,
+    chunk_duration_minutes: u32,
+    silences: &[SilenceInterval],
+    window_seconds: f64,
+) -> Result<Vec<ChunkInfo>, String> {
+    let nominal = calculate_chunks_for_duration(duration_seconds, chunk_duration_minutes)?;
+    let mut chunks: Vec<ChunkInfo> = Vec::with_capacity(nominal.len());
+    let mut start = 0.0;
+
+    for (index, chunk) in nominal.iter().enumerate() {
+        let end = if index + 1 == nominal.len() {
+            duration_seconds
+        } else {
+            nearest_silence_cut(chunk.end_time_seconds, silences, window_seconds)
+                .filter(|cut| *cut > start && *cut < duration_seconds)
+                .unwrap_or(chunk.end_time_seconds)
+        };
+
+        chunks.push(ChunkInfo::new(chunk.chunk_number, start, end));
+        start = end;
+    }
+
+    Ok(chunks)
+}
+
+pub fn calculate_chunks_with_overlap(
+    duration_seconds: f64,
+    chunk_duration_minutes: u32,
+    overlap_seconds: f64,
+) -> Result<Vec<ChunkInfo>, String> {
+    if !overlap_seconds.is_finite() || overlap_seconds < 0.0 {
+        return Err("Chunk overlap must be zero or a positive number of seconds.".to_string());
+    }
+
+    let mut chunks = calculate_chunks_for_duration(duration_seconds, chunk_duration_minutes)?;
+    for chunk in &mut chunks {
+        chunk.padded_start_seconds = (chunk.start_time_seconds - overlap_seconds).max(0.0);
+        chunk.padded_end_seconds = (chunk.end_time_seconds + overlap_seconds).min(duration_seconds);
+        chunk.expected_size_bytes = estimate_upload_bytes(chunk.padded_duration_seconds());
+    }
+
+    Ok(chunks)
+}
+
+#[cfg(feature = "gui")]
 #[tauri::command]
 pub fn select_video_file(app: AppHandle) -> Result<Option<String>, String> {
     let selected = app
@@ -185,6 +611,32 @@ pub fn select_video_file(app: AppHandle) -> Result<Option<String>, String> {
     Ok(Some(path_to_string(&path)))
 }
 
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub fn select_video_files(app: AppHandle) -> Result<Vec<String>, String> {
+    let Some(selected) = app
+        .dialog()
+        .file()
+        .set_title("Select video files")
+        .add_filter("Video files", VIDEO_EXTENSIONS)
+        .blocking_pick_files()
+    else {
+        return Ok(Vec::new());
+    };
+
+    selected
+        .into_iter()
+        .map(|file| {
+            let path = file
+                .into_path()
+                .map_err(|error| format!("Failed to resolve selected file path: {error}"))?;
+            validate_video_path(&path)?;
+            Ok(path_to_string(&path))
+        })
+        .collect()
+}
+
+#[cfg(feature = "gui")]
 #[tauri::command]
 pub async fn validate_video_file(path: String) -> Result<VideoFileInfo, String> {
     let path = PathBuf::from(path);
@@ -207,8 +659,11 @@ pub async fn validate_video_file(path: String) -> Result<VideoFileInfo, String>
     })
 }
 
-#[tauri::command]
-pub async fn get_video_info(path: String, app: AppHandle) -> Result<VideoInfo, String> {
+pub(crate) async fn probe_video(
+    sidecars: &Sidecars,
+    path: String,
+    cancel: Option<&CancellationToken>,
+) -> Result<VideoInfo, String> {
     let path = PathBuf::from(path);
     validate_video_path(&path)?;
 
@@ -224,78 +679,145 @@ pub async fn get_video_info(path: String, app: AppHandle) -> Result<VideoInfo, S
 
     let input_path = path_to_string(&path);
 
-    let output = execute_ffprobe_command(
-        &app,
-        &[
-            "-v",
-            "quiet",
-            "-print_format",
-            "json",
-            "-show_format",
-            "-show_streams",
-            &input_path,
-        ],
-        Duration::from_secs(30),
-    )
-    .await
+    let args = [
+        "-v",
+        "quiet",
+        "-print_format",
+        "json",
+        "-show_format",
+        "-show_streams",
+        "-show_chapters",
+        &input_path,
+    ];
+    let timeout = Duration::from_secs(30);
+
+    let output = match cancel {
+        Some(cancel) => execute_ffprobe_command_with_cancel(sidecars, &args, timeout, cancel).await,
+        None => execute_ffprobe_command(sidecars, &args, timeout).await,
+    }
     .map_err(|error| format!("Failed to probe video file: {error}"))?;
 
     let parsed: FFprobeOutput = serde_json::from_str(&output.stdout)
         .map_err(|error| format!("Failed to parse ffprobe output: {error}"))?;
 
-    let duration_seconds = parse_duration(parsed.format.as_ref().and_then(|f| f.duration.clone()))?;
-    let format_name = parsed
-        .format
-        .as_ref()
+    video_info_from_probe(parsed, input_path, file_name, metadata.len())
+}
+
+fn video_info_from_probe(
+    parsed: FFprobeOutput,
+    path: String,
+    file_name: String,
+    size_bytes: u64,
+) -> Result<VideoInfo, String> {
+    let format = parsed.format.as_ref();
+    let duration_seconds = parse_duration(format.and_then(|f| f.duration.clone()))?;
+    let format_name = format
         .and_then(|f| f.format_name.clone())
         .unwrap_or_else(|| "unknown".to_string());
 
-    let mut codec_name = "unknown".to_string();
-    let mut width = None;
-    let mut height = None;
-
-    if let Some(streams) = parsed.streams {
-        if let Some(video_stream) = streams
-            .into_iter()
-            .find(|stream| stream.codec_type.as_deref() == Some("video"))
-        {
-            codec_name = video_stream
-                .codec_name
-                .unwrap_or_else(|| "unknown".to_string());
-            width = video_stream.width;
-            height = video_stream.height;
-        }
-    }
-
-    Ok(VideoInfo {
-        path: input_path,
+    let mut info = VideoInfo {
+        path,
         file_name,
-        size_bytes: metadata.len(),
+        size_bytes,
         duration_seconds,
         format_name,
-        codec_name,
-        width,
-        height,
-    })
+        codec_name: "unknown".to_string(),
+        width: None,
+        height: None,
+        bit_rate: parse_number(format.and_then(|f| f.bit_rate.as_deref())),
+        start_time_seconds: parse_number(format.and_then(|f| f.start_time.as_deref()))
+            .unwrap_or(0.0),
+        video_bit_rate: None,
+        frame_rate: None,
+        variable_frame_rate: false,
+        rotation: 0,
+        audio_streams: Vec::new(),
+        subtitle_streams: Vec::new(),
+        chapters: Vec::new(),
+    };
+    let mut found_video = false;
+
+    for (position, stream) in parsed.streams.unwrap_or_default().into_iter().enumerate() {
+        match stream.codec_type.as_deref() {
+            Some("video") if !found_video => {
+                found_video = true;
+                let base_rate = parse_frame_rate(stream.r_frame_rate.as_deref());
+                let average_rate = parse_frame_rate(stream.avg_frame_rate.as_deref());
+                info.frame_rate = average_rate.or(base_rate);
+                info.variable_frame_rate = is_variable_frame_rate(base_rate, average_rate);
+                info.rotation = stream.rotation();
+                info.video_bit_rate = parse_number(stream.bit_rate.as_deref());
+                info.width = stream.width;
+                info.height = stream.height;
+                info.codec_name = stream.codec_name.unwrap_or_else(|| "unknown".to_string());
+            }
+            Some("audio") => info.audio_streams.push(stream.audio_stream_info(position)),
+            Some("subtitle") => info
+                .subtitle_streams
+                .push(stream.subtitle_stream_info(position)),
+            _ => {}
+        }
+    }
+
+    // ffprobe reports `end_time` as `N/A` for some containers; such a chapter runs until the
+    // next one starts, or to the end of the video.
+    let chapters: Vec<(f64, Option<f64>, Option<String>)> = parsed
+        .chapters
+        .into_iter()
+        .filter_map(|chapter| {
+            Some((
+                parse_number(chapter.start_time.as_deref())?,
+                parse_number(chapter.end_time.as_deref()),
+                parse_tag(chapter.tags.title),
+            ))
+        })
+        .collect();
+    let mut next_start = duration_seconds;
+    for (start_seconds, end_seconds, title) in chapters.into_iter().rev() {
+        info.chapters.push(ChapterInfo {
+            start_seconds,
+            end_seconds: end_seconds.unwrap_or(next_start),
+            title,
+        });
+        next_start = start_seconds;
+    }
+    info.chapters.reverse();
+
+    Ok(info)
 }
 
+#[cfg(feature = "gui")]
 #[tauri::command]
-pub fn calculate_chunks(duration_seconds: f64, chunk_duration_minutes: u32) -> Result<Vec<ChunkInfo>, String> {
-    calculate_chunks_for_duration(duration_seconds, chunk_duration_minutes)
+pub async fn get_video_info(path: String, app: AppHandle) -> Result<VideoInfo, String> {
+    probe_video(&Sidecars::from_app(&app), path, None).await
 }
 
+#[cfg(feature = "gui")]
 #[tauri::command]
-pub fn estimate_cost(duration_seconds: f64) -> Result<CostEstimate, String> {
+pub async fn calculate_chunks(
+    app: AppHandle,
+    duration_seconds: f64,
+    chunk_duration_minutes: u32,
+) -> Result<ChunkPlan, String> {
+    let settings = get_settings(app).await?;
+    chunk_plan_for(&settings, duration_seconds, chunk_duration_minutes)
+}
+
+pub(crate) fn estimate_cost_for(
+    settings: &AppSettings,
+    duration_seconds: f64,
+) -> Result<CostEstimate, String> {
     if !duration_seconds.is_finite() || duration_seconds <= 0.0 {
         return Err("Duration must be a positive number of seconds.".to_string());
     }
 
+    let rate_per_minute_usd = capabilities_for(settings).price_per_minute_usd;
     let duration_minutes = duration_seconds / 60.0;
-    let total_cost_usd = duration_minutes * COST_RATE_PER_MINUTE_USD;
+    let total_cost_usd = duration_minutes * rate_per_minute_usd;
     let exceeds_warning_threshold = total_cost_usd >= COST_WARNING_THRESHOLD_USD;
 
     Ok(CostEstimate {
-        rate_per_minute_usd: COST_RATE_PER_MINUTE_USD,
+        rate_per_minute_usd,
         duration_minutes,
         total_cost_usd,
         formatted_cost: format!("${total_cost_usd:.2} USD"),
@@ -304,6 +826,14 @@ pub fn estimate_cost(duration_seconds: f64) -> Result<CostEstimate, String> {
     })
 }
 
+#[cfg(feature = "gui")]
+#[tauri::command]
+pub async fn estimate_cost(app: AppHandle, duration_seconds: f64) -> Result<CostEstimate, String> {
+    let settings = get_settings(app).await?;
+    estimate_cost_for(&settings, duration_seconds)
+}
+
+#[cfg(feature = "gui")]
 #[tauri::command]
 pub fn select_output_folder(app: AppHandle) -> Result<Option<String>, String> {
     let selected = app
@@ -339,7 +869,21 @@ pub fn select_output_folder(app: AppHandle) -> Result<Option<String>, String> {
 
 #[cfg(test)]
 mod tests {
-    use super::calculate_chunks_for_duration;
+    use super::{
+        calculate_chunks_for_duration, calculate_chunks_with_overlap,
+        calculate_chunks_with_silences, chunk_plan_for, ensure_chunks_fit_upload_limit,
+        resolve_audio_stream, upload_safe_chunk_minutes, video_info_from_probe, AudioStreamInfo,
+        FFprobeOutput,
+    };
+    use crate::models::settings::{AppSettings, ChunkingMode, ProviderKind};
+    use crate::utils::ffmpeg::SilenceInterval;
+
+    fn silence(start_seconds: f64, end_seconds: f64) -> SilenceInterval {
+        SilenceInterval {
+            start_seconds,
+            end_seconds,
+        }
+    }
 
     #[test]
     fn returns_single_chunk_when_video_shorter_than_chunk_duration() {
@@ -368,4 +912,214 @@ mod tests {
         assert!((chunks[2].start_time_seconds - 1200.0).abs() < 0.001);
         assert!((chunks[2].end_time_seconds - 1500.0).abs() < 0.001);
     }
+
+    #[test]
+    fn moves_boundaries_to_nearest_silence_within_window() {
+        let silences = [
+            silence(570.0, 571.0),
+            silence(610.0, 612.0),
+            silence(1199.0, 1203.0),
+        ];
+        let chunks = calculate_chunks_with_silences(1500.0, 10, &silences, 30.0)
+            .expect("chunk calculation should succeed");
+
+        assert_eq!(chunks.len(), 3);
+        assert!((chunks[0].end_time_seconds - 611.0).abs() < 0.001);
+        assert!((chunks[1].start_time_seconds - 611.0).abs() < 0.001);
+        assert!((chunks[1].end_time_seconds - 1200.0).abs() < 0.001);
+        assert!((chunks[2].end_time_seconds - 1500.0).abs() < 0.001);
+        assert!(chunks
+            .windows(2)
+            .all(|pair| pair[0].end_time_seconds == pair[1].start_time_seconds));
+        assert!(chunks
+            .iter()
+            .all(|chunk| (chunk.duration_seconds
+                - (chunk.end_time_seconds - chunk.start_time_seconds))
+                .abs()
+                < 0.001));
+    }
+
+    #[test]
+    fn keeps_nominal_cut_when_no_silence_is_close_enough() {
+        let silences = [silence(500.0, 501.0), silence(1495.0, 1500.0)];
+        let chunks = calculate_chunks_with_silences(1500.0, 10, &silences, 30.0)
+            .expect("chunk calculation should succeed");
+
+        assert!((chunks[0].end_time_seconds - 600.0).abs() < 0.001);
+        assert!((chunks[1].end_time_seconds - 1200.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn pads_chunks_without_moving_nominal_boundaries() {
+        let chunks = calculate_chunks_with_overlap(1500.0, 10, 15.0)
+            .expect("chunk calculation should succeed");
+
+        assert_eq!(chunks.len(), 3);
+        assert!((chunks[0].padded_start_seconds - 0.0).abs() < 0.001);
+        assert!((chunks[0].padded_end_seconds - 615.0).abs() < 0.001);
+        assert!((chunks[1].start_time_seconds - 600.0).abs() < 0.001);
+        assert!((chunks[1].padded_start_seconds - 585.0).abs() < 0.001);
+        assert!((chunks[2].padded_end_seconds - 1500.0).abs() < 0.001);
+        assert!((chunks[1].padded_duration_seconds() - 630.0).abs() < 0.001);
+        assert!(calculate_chunks_with_overlap(1500.0, 10, -1.0).is_err());
+    }
+
+    #[test]
+    fn shrinks_chunks_that_would_exceed_upload_limit() {
+        let limit = 25 * 1024 * 1024;
+        assert_eq!(upload_safe_chunk_minutes(20, 0.0, limit), 20);
+        assert_eq!(upload_safe_chunk_minutes(60, 0.0, limit), 53);
+        assert_eq!(upload_safe_chunk_minutes(60, 120.0, limit), 51);
+        assert_eq!(upload_safe_chunk_minutes(60, 0.0, 10 * 1024 * 1024), 21);
+
+        let oversized = calculate_chunks_for_duration(7200.0, 60).expect("chunks");
+        assert!(oversized[0].expected_size_bytes > limit);
+        assert!(ensure_chunks_fit_upload_limit(&oversized, limit).is_err());
+
+        let planned =
+            calculate_chunks_for_duration(7200.0, upload_safe_chunk_minutes(60, 0.0, limit))
+                .expect("chunks");
+        assert_eq!(planned.len(), 3);
+        assert!(ensure_chunks_fit_upload_limit(&planned, limit).is_ok());
+    }
+
+    #[test]
+    fn plans_chunks_with_the_padding_of_the_chunking_mode() {
+        let settings = AppSettings {
+            chunking_mode: ChunkingMode::Overlap,
+            chunk_overlap_seconds: 60,
+            ..AppSettings::default()
+        };
+
+        let plan = chunk_plan_for(&settings, 7200.0, 60).expect("plan");
+        assert_eq!(plan.chunk_duration_minutes, 51);
+        assert!(plan
+            .notice
+            .as_deref()
+            .is_some_and(|notice| notice.contains("60 to 51")));
+        assert!((plan.chunks[1].padded_start_seconds - 3000.0).abs() < 0.001);
+        assert!(ensure_chunks_fit_upload_limit(&plan.chunks, 25 * 1024 * 1024).is_ok());
+
+        let short = chunk_plan_for(&settings, 7200.0, 20).expect("plan");
+        assert_eq!(short.chunk_duration_minutes, 20);
+        assert!(short.notice.is_none());
+
+        let offline = AppSettings {
+            provider: ProviderKind::WhisperCpp,
+            ..settings
+        };
+        let plan = chunk_plan_for(&offline, 7200.0, 60).expect("plan");
+        assert_eq!(plan.chunks.len(), 2);
+        assert!(plan.notice.is_none());
+    }
+
+    #[test]
+    fn reports_expected_size_for_padded_range() {
+        let chunks = calculate_chunks_with_overlap(1500.0, 10, 15.0).expect("chunks");
+        assert!(chunks[0].expected_size_bytes.abs_diff(5_018_400) <= 1);
+        assert!(chunks[1].expected_size_bytes.abs_diff(5_140_800) <= 1);
+    }
+
+    fn audio(index: u32, language: Option<&str>, is_default: bool) -> AudioStreamInfo {
+        AudioStreamInfo {
+            index,
+            codec_name: "aac".to_string(),
+            channels: Some(2),
+            channel_layout: None,
+            sample_rate: None,
+            bit_rate: None,
+            language: language.map(str::to_string),
+            title: None,
+            is_default,
+        }
+    }
+
+    #[test]
+    fn resolves_audio_stream_by_language_then_default() {
+        let streams = vec![
+            audio(1, Some("eng"), true),
+            audio(2, Some("ger"), false),
+            audio(3, Some("fre"), false),
+        ];
+
+        assert_eq!(resolve_audio_stream(&streams, None, "de"), Ok(2));
+        assert_eq!(resolve_audio_stream(&streams, None, "ja"), Ok(1));
+        assert_eq!(resolve_audio_stream(&streams, Some(3), "de"), Ok(3));
+        assert!(resolve_audio_stream(&streams, Some(7), "de").is_err());
+        assert!(resolve_audio_stream(&[], None, "en").is_err());
+
+        let untagged = vec![audio(1, None, false), audio(2, None, false)];
+        assert_eq!(resolve_audio_stream(&untagged, None, "en"), Ok(1));
+    }
+
+    #[test]
+    fn reads_stream_details_and_chapters_from_ffprobe_json() {
+        let json = r#"{
+            "streams": [
+                { "index": 0, "codec_type": "video", "codec_name": "h264", "width": 1920,
+                  "height": 1080, "bit_rate": "4000000", "r_frame_rate": "60/1",
+                  "avg_frame_rate": "10860000/362401",
+                  "side_data_list": [{ "side_data_type": "Display Matrix", "rotation": -90 }] },
+                { "index": 1, "codec_type": "audio", "codec_name": "aac", "channels": 2,
+                  "channel_layout": "stereo", "sample_rate": "48000", "bit_rate": "128000",
+                  "tags": { "language": "eng" }, "disposition": { "default": 1, "forced": 0 } },
+                { "index": 2, "codec_type": "subtitle", "codec_name": "subrip",
+                  "tags": { "language": "ger", "title": "Forced" },
+                  "disposition": { "default": 0, "forced": 1 } }
+            ],
+            "chapters": [
+                { "id": 0, "start_time": "0.000000", "end_time": "95.500000",
+                  "tags": { "title": "Intro" } },
+                { "id": 1, "start_time": "95.500000", "tags": { "title": "Talk" } },
+                { "id": 2, "start_time": "600.000000", "end_time": "N/A" }
+            ],
+            "format": { "format_name": "mov,mp4,m4a,3gp,3g2,mj2", "duration": "1200.5",
+                        "start_time": "0.023220", "bit_rate": "4200000" }
+        }"#;
+        let parsed: FFprobeOutput = serde_json::from_str(json).expect("valid ffprobe json");
+
+        let info = video_info_from_probe(parsed, "/v.mp4".to_string(), "v.mp4".to_string(), 1)
+            .expect("video info");
+        assert_eq!(info.bit_rate, Some(4_200_000));
+        assert!((info.start_time_seconds - 0.02322).abs() < 1e-9);
+        assert_eq!(info.video_bit_rate, Some(4_000_000));
+        assert!((info.frame_rate.expect("frame rate") - 29.967).abs() < 0.001);
+        assert!(info.variable_frame_rate);
+        assert_eq!(info.rotation, 90);
+
+        let audio = &info.audio_streams[0];
+        assert_eq!(audio.sample_rate, Some(48_000));
+        assert_eq!(audio.channel_layout.as_deref(), Some("stereo"));
+        assert_eq!(audio.bit_rate, Some(128_000));
+
+        assert_eq!(info.subtitle_streams.len(), 1);
+        assert_eq!(info.subtitle_streams[0].index, 2);
+        assert_eq!(info.subtitle_streams[0].language.as_deref(), Some("ger"));
+        assert!(info.subtitle_streams[0].is_forced);
+        assert!(info.subtitle_streams[0].is_text);
+
+        assert_eq!(info.chapters.len(), 3);
+        assert_eq!(info.chapters[0].title.as_deref(), Some("Intro"));
+        assert!((info.chapters[0].end_seconds - 95.5).abs() < 1e-9);
+        assert_eq!(info.chapters[1].title.as_deref(), Some("Talk"));
+        assert!((info.chapters[1].end_seconds - 600.0).abs() < 1e-9);
+        assert_eq!(info.chapters[2].title, None);
+        assert!((info.chapters[2].end_seconds - 1200.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn treats_matching_frame_rates_as_constant() {
+        let json = r#"{
+            "streams": [{ "codec_type": "video", "r_frame_rate": "30000/1001",
+                          "avg_frame_rate": "30000/1001", "tags": { "rotate": "270" } }],
+            "format": { "duration": "10.0" }
+        }"#;
+        let parsed: FFprobeOutput = serde_json::from_str(json).expect("valid ffprobe json");
+
+        let info = video_info_from_probe(parsed, "/v.mkv".to_string(), "v.mkv".to_string(), 1)
+            .expect("video info");
+        assert!(!info.variable_frame_rate);
+        assert_eq!(info.rotation, 270);
+        assert!(info.chapters.is_empty());
+    }
 }
@@ -0,0 +1,433 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_dialog::DialogExt;
+use tokio::fs;
+use tokio::sync::MutexGuard;
+
+use crate::commands::processing::resolve_output_path;
+use crate::commands::queue::enqueue_watched_file;
+use crate::commands::settings::get_settings;
+use crate::commands::video::{path_to_string, validate_video_path};
+use crate::models::job::unix_timestamp;
+use crate::models::queue::JobOverrides;
+use crate::models::settings::AppSettings;
+use crate::models::watch::{
+    FileSnapshot, WatchFolder, WatchFolderState, WatchRuntime, WATCH_FOLDERS_UPDATED_EVENT,
+};
+
+const WATCH_FOLDERS_FILE_NAME: &str = "watch_folders.json";
+const SCAN_INTERVAL: Duration = Duration::from_secs(10);
+
+fn watch_folders_file_path(app: &AppHandle) -> Result<PathBuf> {
+    let mut dir = app
+        .path()
+        .app_data_dir()
+        .context("failed to resolve app data directory")?;
+
+    dir.push(WATCH_FOLDERS_FILE_NAME);
+    Ok(dir)
+}
+
+async fn read_folders_from_disk(app: &AppHandle) -> Result<Vec<WatchFolder>> {
+    let file_path = watch_folders_file_path(app)?;
+    if !fs::try_exists(&file_path).await.unwrap_or(false) {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .await
+        .with_context(|| format!("failed to read watch folders {}", file_path.display()))?;
+
+    serde_json::from_str(&content)
+        .with_context(|| format!("failed to parse watch folders {}", file_path.display()))
+}
+
+async fn write_folders_to_disk(app: &AppHandle, folders: &[WatchFolder]) -> Result<()> {
+    let file_path = watch_folders_file_path(app)?;
+    if let Some(parent) = file_path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .context("failed to create app data directory")?;
+    }
+
+    let content =
+        serde_json::to_string_pretty(folders).context("failed to serialize watch folders")?;
+    let temp_path = file_path.with_extension("tmp");
+
+    fs::write(&temp_path, content)
+        .await
+        .with_context(|| format!("failed to write watch folders {}", temp_path.display()))?;
+    fs::rename(&temp_path, &file_path)
+        .await
+        .with_context(|| format!("failed to replace watch folders {}", file_path.display()))?;
+
+    Ok(())
+}
+
+async fn lock_runtime<'a>(
+    app: &AppHandle,
+    state: &'a WatchFolderState,
+) -> Result<MutexGuard<'a, WatchRuntime>, String> {
+    let mut runtime = state.runtime.lock().await;
+    if !runtime.loaded {
+        runtime.folders = read_folders_from_disk(app)
+            .await
+            .map_err(|error| error.to_string())?;
+        runtime.loaded = true;
+    }
+
+    Ok(runtime)
+}
+
+async fn persist(app: &AppHandle, runtime: &WatchRuntime) -> Result<Vec<WatchFolder>, String> {
+    write_folders_to_disk(app, &runtime.folders)
+        .await
+        .map_err(|error| format!("Failed to save watch folders: {error}"))?;
+
+    if let Err(error) = app.emit(WATCH_FOLDERS_UPDATED_EVENT, runtime.folders.clone()) {
+        eprintln!("[watch] failed to emit watch folder update: {error}");
+    }
+
+    Ok(runtime.folders.clone())
+}
+
+async fn list_video_files(dir: &Path) -> Result<Vec<(PathBuf, FileSnapshot)>> {
+    let mut entries = fs::read_dir(dir)
+        .await
+        .with_context(|| format!("failed to read watch folder {}", dir.display()))?;
+    let mut files = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if validate_video_path(&path).is_err() {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata().await else {
+            continue;
+        };
+        files.push((
+            path,
+            FileSnapshot {
+                size: metadata.len(),
+                modified: metadata.modified().ok(),
+            },
+        ));
+    }
+
+    Ok(files)
+}
+
+fn ensure_folder(path: &str, label: &str) -> Result<(), String> {
+    if !Path::new(path).is_dir() {
+        return Err(format!("{label} does not exist or is not a folder: {path}"));
+    }
+
+    Ok(())
+}
+
+fn same_folder(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// The archive folder to store for `watched_path`, `None` when left blank. Archiving into
+/// the watched folder itself would queue every processed file again.
+fn validate_archive_folder(
+    watched_path: &str,
+    archive_folder: Option<String>,
+) -> Result<Option<String>, String> {
+    let archive_folder = archive_folder.filter(|folder| !folder.trim().is_empty());
+    if let Some(archive_folder) = &archive_folder {
+        ensure_folder(archive_folder, "Archive folder")?;
+        if same_folder(Path::new(archive_folder), Path::new(watched_path)) {
+            return Err("The archive folder must differ from the watched folder.".to_string());
+        }
+    }
+
+    Ok(archive_folder)
+}
+
+fn transcript_exists(video_path: &Path, folder: &WatchFolder, settings: &AppSettings) -> bool {
+    let config = folder
+        .overrides
+        .resolve(&path_to_string(video_path), settings);
+
+    resolve_output_path(
+        video_path,
+        config.output_folder.as_deref(),
+        config.output_format.unwrap_or_default(),
+    )
+    .is_ok_and(|output_path| output_path.exists())
+}
+
+async fn scan_folders(app: &AppHandle) -> Result<(), String> {
+    let settings = get_settings(app.clone()).await?;
+    let state = app.state::<WatchFolderState>();
+    let mut runtime = lock_runtime(app, &state).await?;
+    let folders: Vec<WatchFolder> = runtime
+        .folders
+        .iter()
+        .filter(|folder| folder.enabled)
+        .cloned()
+        .collect();
+
+    let mut present = HashSet::new();
+    let mut settled = Vec::new();
+    for folder in &folders {
+        let files = match list_video_files(Path::new(&folder.path)).await {
+            Ok(files) => files,
+            Err(error) => {
+                eprintln!("[watch] {error}");
+                continue;
+            }
+        };
+
+        for (path, snapshot) in files {
+            present.insert(path.clone());
+            if folder.was_present_at_registration(&path) {
+                continue;
+            }
+            if runtime.tracker.observe(&path, snapshot) {
+                settled.push((folder, path));
+            }
+        }
+    }
+    runtime.tracker.retain_present(&present);
+    drop(runtime);
+
+    for (folder, path) in settled {
+        if transcript_exists(&path, folder, &settings) {
+            eprintln!(
+                "[watch] skipping {}: transcript already exists",
+                path.display()
+            );
+            continue;
+        }
+
+        match enqueue_watched_file(app, path_to_string(&path), folder).await {
+            Ok(true) => eprintln!("[watch] queued {}", path.display()),
+            Ok(false) => {}
+            Err(error) => eprintln!("[watch] failed to queue {}: {error}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Polls the registered folders for the lifetime of the app. Polling rather than file system
+/// notifications keeps network shares working and makes "stopped growing" easy to detect.
+pub async fn run_watch_worker(app: AppHandle) {
+    loop {
+        if let Err(error) = scan_folders(&app).await {
+            eprintln!("[watch] scan failed: {error}");
+        }
+
+        tokio::time::sleep(SCAN_INTERVAL).await;
+    }
+}
+
+fn archive_target(archive_dir: &Path, file_name: &str) -> PathBuf {
+    let target = archive_dir.join(file_name);
+    if !target.exists() {
+        return target;
+    }
+
+    let source = Path::new(file_name);
+    let stem = source
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(file_name);
+    match source.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => archive_dir.join(format!("{stem}-{}.{extension}", unix_timestamp())),
+        None => archive_dir.join(format!("{stem}-{}", unix_timestamp())),
+    }
+}
+
+/// Moves a processed video into its watch folder's archive folder, if one is configured.
+pub(crate) async fn archive_processed_file(
+    app: &AppHandle,
+    folder_id: &str,
+    video_path: &str,
+) -> Result<(), String> {
+    let state = app.state::<WatchFolderState>();
+    let archive_folder = lock_runtime(app, &state)
+        .await?
+        .folders
+        .iter()
+        .find(|folder| folder.id == folder_id)
+        .and_then(|folder| folder.archive_folder.clone());
+    let Some(archive_folder) = archive_folder else {
+        return Ok(());
+    };
+
+    let source = Path::new(video_path);
+    let file_name = source
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| format!("Unable to determine the file name of {video_path}"))?;
+    let archive_dir = Path::new(&archive_folder);
+    fs::create_dir_all(archive_dir)
+        .await
+        .map_err(|error| format!("Failed to create archive folder {archive_folder}: {error}"))?;
+    let target = archive_target(archive_dir, file_name);
+
+    // Renaming fails across drives, so fall back to copying.
+    if fs::rename(source, &target).await.is_err() {
+        fs::copy(source, &target)
+            .await
+            .map_err(|error| format!("Failed to copy into archive: {error}"))?;
+        fs::remove_file(source)
+            .await
+            .map_err(|error| format!("Failed to remove archived original: {error}"))?;
+    }
+
+    eprintln!("[watch] archived {video_path} to {}", target.display());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn select_watch_folder(app: AppHandle) -> Result<Option<String>, String> {
+    let selected = app
+        .dialog()
+        .file()
+        .set_title("Select folder to watch")
+        .blocking_pick_folder();
+
+    let Some(selected) = selected else {
+        return Ok(None);
+    };
+
+    let path = selected
+        .into_path()
+        .map_err(|error| format!("Failed to resolve selected folder path: {error}"))?;
+
+    Ok(Some(path_to_string(&path)))
+}
+
+#[tauri::command]
+pub async fn list_watch_folders(
+    app: AppHandle,
+    state: State<'_, WatchFolderState>,
+) -> Result<Vec<WatchFolder>, String> {
+    let runtime = lock_runtime(&app, &state).await?;
+    Ok(runtime.folders.clone())
+}
+
+#[tauri::command]
+pub async fn add_watch_folder(
+    app: AppHandle,
+    state: State<'_, WatchFolderState>,
+    path: String,
+    overrides: JobOverrides,
+    archive_folder: Option<String>,
+    include_existing: bool,
+) -> Result<Vec<WatchFolder>, String> {
+    ensure_folder(&path, "Watch folder")?;
+    let archive_folder = validate_archive_folder(&path, archive_folder)?;
+
+    let mut runtime = lock_runtime(&app, &state).await?;
+    if runtime
+        .folders
+        .iter()
+        .any(|folder| Path::new(&folder.path) == Path::new(&path))
+    {
+        return Err(format!("{path} is already being watched."));
+    }
+
+    let existing_files = if include_existing {
+        Vec::new()
+    } else {
+        list_video_files(Path::new(&path))
+            .await
+            .map_err(|error| error.to_string())?
+            .into_iter()
+            .filter_map(|(file, _)| {
+                file.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(str::to_string)
+            })
+            .collect()
+    };
+
+    runtime.folders.push(WatchFolder::new(
+        path,
+        overrides,
+        archive_folder,
+        existing_files,
+    ));
+    persist(&app, &runtime).await
+}
+
+#[tauri::command]
+pub async fn update_watch_folder(
+    app: AppHandle,
+    state: State<'_, WatchFolderState>,
+    folder: WatchFolder,
+) -> Result<Vec<WatchFolder>, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    let existing = runtime
+        .folders
+        .iter_mut()
+        .find(|existing| existing.id == folder.id)
+        .ok_or_else(|| "Watch folder not found.".to_string())?;
+    let archive_folder = validate_archive_folder(&existing.path, folder.archive_folder)?;
+
+    existing.enabled = folder.enabled;
+    existing.overrides = folder.overrides;
+    existing.archive_folder = archive_folder;
+
+    persist(&app, &runtime).await
+}
+
+#[tauri::command]
+pub async fn remove_watch_folder(
+    app: AppHandle,
+    state: State<'_, WatchFolderState>,
+    folder_id: String,
+) -> Result<Vec<WatchFolder>, String> {
+    let mut runtime = lock_runtime(&app, &state).await?;
+    let count = runtime.folders.len();
+    runtime.folders.retain(|folder| folder.id != folder_id);
+    if runtime.folders.len() == count {
+        return Err("Watch folder not found.".to_string());
+    }
+
+    persist(&app, &runtime).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_archive_folder;
+
+    #[test]
+    fn rejects_archiving_into_the_watched_folder() {
+        let watched = std::env::temp_dir().join("watch-folder-archive-test");
+        let archive = watched.join("done");
+        std::fs::create_dir_all(&archive).expect("create folders");
+        let watched_path = watched.to_string_lossy().to_string();
+        let archive_path = archive.to_string_lossy().to_string();
+
+        assert_eq!(
+            validate_archive_folder(&watched_path, Some(archive_path.clone())),
+            Ok(Some(archive_path))
+        );
+        assert_eq!(
+            validate_archive_folder(&watched_path, Some("  ".to_string())),
+            Ok(None)
+        );
+        assert!(validate_archive_folder(&watched_path, Some(format!("{watched_path}/"))).is_err());
+        assert!(validate_archive_folder(
+            &watched_path,
+            Some(archive.join("..").to_string_lossy().to_string())
+        )
+        .is_err());
+        let _ = std::fs::remove_dir_all(&watched);
+    }
+}
@@ -1,10 +1,13 @@
+pub mod cli;
 mod commands;
 mod models;
+mod providers;
 mod utils;
 
-#[cfg(target_os = "windows")]
+#[cfg(all(feature = "gui", target_os = "windows"))]
 use std::process::Command;
 
+#[cfg(feature = "gui")]
 fn load_env() {
     #[cfg(debug_assertions)]
     {
@@ -14,6 +17,7 @@ fn load_env() {
     }
 }
 
+#[cfg(feature = "gui")]
 fn log_ffmpeg_version() {
     #[cfg(target_os = "windows")]
     {
@@ -38,6 +42,7 @@ fn log_ffmpeg_version() {
     }
 }
 
+#[cfg(feature = "gui")]
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     load_env();
@@ -45,20 +50,50 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
-        .setup(|_| {
+        .manage(models::processing::ProcessingJobState::default())
+        .manage(models::queue::JobQueueState::default())
+        .manage(models::watch::WatchFolderState::default())
+        .setup(|app| {
             log_ffmpeg_version();
+            tauri::async_runtime::spawn(commands::queue::run_queue_worker(app.handle().clone()));
+            tauri::async_runtime::spawn(commands::watch::run_watch_worker(app.handle().clone()));
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             commands::settings::get_settings,
             commands::settings::save_settings,
+            commands::settings::get_provider_capabilities,
+            commands::settings::select_model_file,
             commands::language::get_supported_languages,
             commands::video::select_video_file,
+            commands::video::select_video_files,
             commands::video::validate_video_file,
             commands::video::get_video_info,
             commands::video::calculate_chunks,
             commands::video::estimate_cost,
-            commands::video::select_output_folder
+            commands::video::select_output_folder,
+            commands::processing::process_video,
+            commands::processing::resume_job,
+            commands::processing::cancel_processing,
+            commands::subtitles::extract_embedded_subtitles,
+            commands::subtitles::select_subtitle_files,
+            commands::subtitles::mux_subtitles,
+            commands::subtitles::burn_in_subtitles,
+            commands::subtitles::render_burn_in_preview,
+            commands::jobs::list_unfinished_jobs,
+            commands::jobs::discard_job,
+            commands::queue::get_queue,
+            commands::queue::add_to_queue,
+            commands::queue::remove_from_queue,
+            commands::queue::reorder_queue,
+            commands::queue::set_queue_paused,
+            commands::queue::pause_queue_job,
+            commands::queue::resume_queue_job,
+            commands::watch::select_watch_folder,
+            commands::watch::list_watch_folders,
+            commands::watch::add_watch_folder,
+            commands::watch::update_watch_folder,
+            commands::watch::remove_watch_folder
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,221 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "gui")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "gui")]
+use crate::commands::video::ChunkInfo;
+#[cfg(feature = "gui")]
+use crate::models::processing::ProcessingConfig;
+#[cfg(feature = "gui")]
+use crate::models::settings::AppSettings;
+#[cfg(feature = "gui")]
+use crate::models::transcript::Segment;
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChunkStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecord {
+    pub chunk: ChunkInfo,
+    pub status: ChunkStatus,
+    pub segments: Vec<Segment>,
+    pub error: Option<String>,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobManifest {
+    pub id: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub input_path: String,
+    pub output_path: String,
+    pub config: ProcessingConfig,
+    pub settings: AppSettings,
+    pub chunks: Vec<ChunkRecord>,
+    pub last_error: Option<String>,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSummary {
+    pub id: String,
+    pub input_path: String,
+    pub output_path: String,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub total_chunks: u32,
+    pub completed_chunks: u32,
+    pub failed_chunks: u32,
+    pub last_error: Option<String>,
+}
+
+#[cfg(feature = "gui")]
+pub fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+pub fn new_job_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+
+    format!("job-{millis}-{:04x}", fastrand::u16(..))
+}
+
+#[cfg(feature = "gui")]
+impl JobManifest {
+    pub fn new(
+        id: String,
+        input_path: String,
+        output_path: String,
+        config: ProcessingConfig,
+        settings: &AppSettings,
+        chunks: Vec<ChunkInfo>,
+    ) -> Self {
+        let now = unix_timestamp();
+        let mut settings = settings.clone();
+        settings.api_key.clear();
+        settings.translation_api_key.clear();
+
+        Self {
+            id,
+            created_at: now,
+            updated_at: now,
+            input_path,
+            output_path,
+            config,
+            settings,
+            chunks: chunks
+                .into_iter()
+                .map(|chunk| ChunkRecord {
+                    chunk,
+                    status: ChunkStatus::Pending,
+                    segments: Vec::new(),
+                    error: None,
+                })
+                .collect(),
+            last_error: None,
+        }
+    }
+
+    pub fn chunk_infos(&self) -> Vec<ChunkInfo> {
+        self.chunks
+            .iter()
+            .map(|record| record.chunk.clone())
+            .collect()
+    }
+
+    pub fn transcripts(&self) -> Vec<Vec<Segment>> {
+        self.chunks
+            .iter()
+            .map(|record| record.segments.clone())
+            .collect()
+    }
+
+    fn count(&self, status: ChunkStatus) -> u32 {
+        self.chunks
+            .iter()
+            .filter(|record| record.status == status)
+            .count() as u32
+    }
+
+    pub fn summary(&self) -> JobSummary {
+        JobSummary {
+            id: self.id.clone(),
+            input_path: self.input_path.clone(),
+            output_path: self.output_path.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            total_chunks: self.chunks.len() as u32,
+            completed_chunks: self.count(ChunkStatus::Completed),
+            failed_chunks: self.count(ChunkStatus::Failed),
+            last_error: self.last_error.clone(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "gui"))]
+mod tests {
+    use super::{new_job_id, ChunkStatus, JobManifest};
+    use crate::commands::video::calculate_chunks_for_duration;
+    use crate::models::processing::ProcessingConfig;
+    use crate::models::settings::AppSettings;
+    use crate::models::transcript::Segment;
+
+    fn manifest() -> JobManifest {
+        let settings = AppSettings {
+            api_key: "sk-secret".to_string(),
+            translation_api_key: "sk-translate".to_string(),
+            ..AppSettings::default()
+        };
+        let config = ProcessingConfig {
+            video_path: "/videos/talk.mp4".to_string(),
+            source_language: "en".to_string(),
+            target_language: None,
+            output_folder: None,
+            output_format: None,
+            audio_stream_index: None,
+        };
+        let chunks = calculate_chunks_for_duration(1500.0, 10).expect("chunks");
+
+        JobManifest::new(
+            new_job_id(),
+            config.video_path.clone(),
+            "/videos/talk.srt".to_string(),
+            config,
+            &settings,
+            chunks,
+        )
+    }
+
+    #[test]
+    fn new_manifest_starts_pending_without_api_key() {
+        let manifest = manifest();
+        assert!(manifest.settings.api_key.is_empty());
+        assert!(manifest.settings.translation_api_key.is_empty());
+        assert!(manifest
+            .chunks
+            .iter()
+            .all(|record| record.status == ChunkStatus::Pending));
+        assert_eq!(manifest.summary().total_chunks, 3);
+    }
+
+    #[test]
+    fn round_trips_chunk_progress_through_json() {
+        let mut manifest = manifest();
+        manifest.chunks[0].status = ChunkStatus::Completed;
+        manifest.chunks[0].segments = vec![Segment {
+            start: 0.0,
+            end: 1.5,
+            text: "hello".to_string(),
+            words: Vec::new(),
+        }];
+        manifest.chunks[1].status = ChunkStatus::Failed;
+        manifest.chunks[1].error = Some("timeout".to_string());
+
+        let json = serde_json::to_string(&manifest).expect("serialize");
+        let restored: JobManifest = serde_json::from_str(&json).expect("deserialize");
+        let summary = restored.summary();
+
+        assert_eq!(summary.completed_chunks, 1);
+        assert_eq!(summary.failed_chunks, 1);
+        assert_eq!(restored.transcripts()[0], manifest.chunks[0].segments);
+        assert_eq!(restored.chunk_infos()[2].chunk_number, 3);
+    }
+}
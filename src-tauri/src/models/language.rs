@@ -7,6 +7,138 @@ pub struct Language {
     pub name: &'static str,
 }
 
+/// English name for a language code, falling back to the code itself.
+pub fn language_name(code: &str) -> String {
+    supported_languages()
+        .into_iter()
+        .find(|language| language.code == code)
+        .map(|language| language.name.to_string())
+        .unwrap_or_else(|| code.to_string())
+}
+
+/// Other spellings of a language code found in container tags: the ISO 639-2 bibliographic
+/// and terminology codes, plus legacy two-letter codes.
+fn code_aliases(code: &str) -> &'static [&'static str] {
+    match code {
+        "af" => &["afr"],
+        "sq" => &["alb", "sqi"],
+        "am" => &["amh"],
+        "ar" => &["ara"],
+        "hy" => &["arm", "hye"],
+        "as" => &["asm"],
+        "az" => &["aze"],
+        "ba" => &["bak"],
+        "eu" => &["baq", "eus"],
+        "be" => &["bel"],
+        "bn" => &["ben"],
+        "bs" => &["bos"],
+        "br" => &["bre"],
+        "bg" => &["bul"],
+        "my" => &["bur", "mya"],
+        "ca" => &["cat"],
+        "zh" => &["chi", "zho"],
+        "hr" => &["hrv"],
+        "cs" => &["cze", "ces"],
+        "da" => &["dan"],
+        "nl" => &["dut", "nld"],
+        "en" => &["eng"],
+        "et" => &["est"],
+        "fo" => &["fao"],
+        "fi" => &["fin"],
+        "fr" => &["fre", "fra"],
+        "gl" => &["glg"],
+        "ka" => &["geo", "kat"],
+        "de" => &["ger", "deu"],
+        "el" => &["gre", "ell"],
+        "gu" => &["guj"],
+        "ht" => &["hat"],
+        "ha" => &["hau"],
+        "haw" => &["haw"],
+        "he" => &["heb", "iw"],
+        "hi" => &["hin"],
+        "hu" => &["hun"],
+        "is" => &["ice", "isl"],
+        "id" => &["ind"],
+        "it" => &["ita"],
+        "ja" => &["jpn"],
+        "jw" => &["jav", "jv"],
+        "kn" => &["kan"],
+        "kk" => &["kaz"],
+        "km" => &["khm"],
+        "ko" => &["kor"],
+        "lo" => &["lao"],
+        "la" => &["lat"],
+        "lv" => &["lav"],
+        "ln" => &["lin"],
+        "lt" => &["lit"],
+        "lb" => &["ltz"],
+        "mk" => &["mac", "mkd"],
+        "mg" => &["mlg"],
+        "ms" => &["may", "msa"],
+        "ml" => &["mal"],
+        "mt" => &["mlt"],
+        "mi" => &["mao", "mri"],
+        "mr" => &["mar"],
+        "mn" => &["mon"],
+        "ne" => &["nep"],
+        "no" => &["nor", "nob", "nb"],
+        "nn" => &["nno"],
+        "oc" => &["oci"],
+        "ps" => &["pus"],
+        "fa" => &["per", "fas"],
+        "pl" => &["pol"],
+        "pt" => &["por"],
+        "pa" => &["pan"],
+        "ro" => &["rum", "ron"],
+        "ru" => &["rus"],
+        "sa" => &["san"],
+        "sr" => &["srp"],
+        "sn" => &["sna"],
+        "sd" => &["snd"],
+        "si" => &["sin"],
+        "sk" => &["slo", "slk"],
+        "sl" => &["slv"],
+        "so" => &["som"],
+        "es" => &["spa"],
+        "su" => &["sun"],
+        "sw" => &["swa"],
+        "sv" => &["swe"],
+        "tl" => &["tgl", "fil"],
+        "tg" => &["tgk"],
+        "ta" => &["tam"],
+        "tt" => &["tat"],
+        "te" => &["tel"],
+        "th" => &["tha"],
+        "bo" => &["tib", "bod"],
+        "tr" => &["tur"],
+        "tk" => &["tuk"],
+        "uk" => &["ukr"],
+        "ur" => &["urd"],
+        "uz" => &["uzb"],
+        "vi" => &["vie"],
+        "cy" => &["wel", "cym"],
+        "yi" => &["yid"],
+        "yo" => &["yor"],
+        _ => &[],
+    }
+}
+
+/// Three-letter ISO 639-2 (bibliographic) code used to tag container streams, e.g. `ger`
+/// for `de`.
+pub fn iso_639_2_code(code: &str) -> Option<&'static str> {
+    code_aliases(code).first().copied()
+}
+
+/// Whether a stream's language tag (`eng`, `en-US`, `ger`, ...) refers to `code`.
+pub fn language_tag_matches(tag: &str, code: &str) -> bool {
+    let tag = tag.trim().to_ascii_lowercase();
+    let primary = tag.split(['-', '_']).next().unwrap_or_default();
+
+    primary == code
+        || code_aliases(code).contains(&primary)
+        || language_name(code).eq_ignore_ascii_case(&tag)
+}
+
 pub fn supported_languages() -> Vec<Language> {
     vec![
         Language {
@@ -407,3 +539,26 @@ pub fn supported_languages() -> Vec<Language> {
         },
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{iso_639_2_code, language_tag_matches};
+
+    #[test]
+    fn matches_container_language_tags() {
+        assert!(language_tag_matches("eng", "en"));
+        assert!(language_tag_matches("en-US", "en"));
+        assert!(language_tag_matches("ger", "de"));
+        assert!(language_tag_matches("deu", "de"));
+        assert!(language_tag_matches("Hebrew", "he"));
+        assert!(!language_tag_matches("spa", "en"));
+        assert!(!language_tag_matches("und", "en"));
+    }
+
+    #[test]
+    fn tags_streams_with_bibliographic_codes() {
+        assert_eq!(iso_639_2_code("de"), Some("ger"));
+        assert_eq!(iso_639_2_code("en"), Some("eng"));
+        assert_eq!(iso_639_2_code("xx"), None);
+    }
+}
@@ -1,2 +1,9 @@
+pub mod job;
 pub mod language;
+pub mod processing;
+pub mod queue;
 pub mod settings;
+pub mod style;
+pub mod transcript;
+#[cfg(feature = "gui")]
+pub mod watch;
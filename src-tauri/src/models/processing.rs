@@ -0,0 +1,127 @@
+#[cfg(feature = "gui")]
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "gui")]
+use tokio_util::sync::CancellationToken;
+
+use crate::models::settings::SubtitleFormat;
+
+#[cfg(feature = "gui")]
+pub const PROGRESS_EVENT: &str = "processing-progress";
+#[cfg(feature = "gui")]
+pub const COMPLETE_EVENT: &str = "processing-complete";
+#[cfg(feature = "gui")]
+pub const ERROR_EVENT: &str = "processing-error";
+#[cfg(feature = "gui")]
+pub const RETRY_EVENT: &str = "processing-retry";
+#[cfg(feature = "gui")]
+pub const CANCELLED_EVENT: &str = "processing-cancelled";
+pub const CANCELLED_MESSAGE: &str = "Processing was cancelled.";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessingConfig {
+    pub video_path: String,
+    pub source_language: String,
+    pub target_language: Option<String>,
+    pub output_folder: Option<String>,
+    #[serde(default)]
+    pub output_format: Option<SubtitleFormat>,
+    /// Absolute ffprobe index of the audio stream to transcribe; picked from the source
+    /// language tag when unset.
+    #[serde(default)]
+    pub audio_stream_index: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(feature = "gui"), allow(dead_code))]
+pub enum ProcessingStage {
+    Analyzing,
+    Extracting,
+    Transcribing,
+    Generating,
+    Translating,
+    Muxing,
+    Rendering,
+    Complete,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessingProgress {
+    pub stage: ProcessingStage,
+    pub chunk_number: u32,
+    pub total_chunks: u32,
+    pub percentage: f64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessingRetry {
+    pub chunk_number: u32,
+    pub total_chunks: u32,
+    pub retry: u32,
+    pub max_retries: u32,
+    pub delay_seconds: f64,
+    pub error: String,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessingResult {
+    pub output_path: String,
+    /// The source language, or English when the provider translated the audio.
+    pub output_language: String,
+    /// `<name>.<target>.srt`, when a target language was requested.
+    pub translated_output_path: Option<String>,
+    pub segment_count: usize,
+    pub chunk_count: u32,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Default)]
+pub struct ProcessingJobState {
+    current: Mutex<Option<CancellationToken>>,
+}
+
+#[cfg(feature = "gui")]
+impl ProcessingJobState {
+    pub fn start(&self) -> Result<CancellationToken, String> {
+        let mut current = self
+            .current
+            .lock()
+            .map_err(|_| "Processing state is unavailable.".to_string())?;
+
+        if current.is_some() {
+            return Err("Another video is already being processed.".to_string());
+        }
+
+        let token = CancellationToken::new();
+        *current = Some(token.clone());
+        Ok(token)
+    }
+
+    pub fn finish(&self) {
+        if let Ok(mut current) = self.current.lock() {
+            *current = None;
+        }
+    }
+
+    pub fn cancel(&self) -> bool {
+        match self.current.lock() {
+            Ok(current) => match current.as_ref() {
+                Some(token) => {
+                    token.cancel();
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+#[cfg(feature = "gui")]
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "gui")]
+use tokio::sync::{Mutex, Notify};
+#[cfg(feature = "gui")]
+use tokio_util::sync::CancellationToken;
+
+#[cfg(feature = "gui")]
+use crate::models::job::{new_job_id, unix_timestamp};
+use crate::models::processing::ProcessingConfig;
+use crate::models::settings::{AppSettings, SubtitleFormat};
+
+#[cfg(feature = "gui")]
+pub const QUEUE_UPDATED_EVENT: &str = "queue-updated";
+#[cfg(feature = "gui")]
+pub const QUEUE_PROGRESS_EVENT: &str = "queue-progress";
+#[cfg(feature = "gui")]
+pub const QUEUE_RETRY_EVENT: &str = "queue-retry";
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Per-job choices that take precedence over `AppSettings`. A target language equal to the
+/// source language turns translation off even when the settings enable it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobOverrides {
+    pub source_language: Option<String>,
+    pub target_language: Option<String>,
+    pub output_folder: Option<String>,
+    pub output_format: Option<SubtitleFormat>,
+    pub audio_stream_index: Option<u32>,
+}
+
+impl JobOverrides {
+    pub fn resolve(&self, video_path: &str, settings: &AppSettings) -> ProcessingConfig {
+        ProcessingConfig {
+            video_path: video_path.to_string(),
+            source_language: non_empty(&self.source_language)
+                .unwrap_or_else(|| settings.source_language.clone()),
+            target_language: non_empty(&self.target_language)
+                .or_else(|| non_empty(&settings.target_language)),
+            output_folder: non_empty(&self.output_folder)
+                .or_else(|| non_empty(&settings.default_output_folder)),
+            output_format: Some(self.output_format.unwrap_or(settings.default_output_format)),
+            audio_stream_index: self.audio_stream_index,
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QueueJobStatus {
+    Queued,
+    Running,
+    Paused,
+    Completed,
+    Failed,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueJob {
+    pub id: String,
+    pub video_path: String,
+    pub overrides: JobOverrides,
+    pub status: QueueJobStatus,
+    pub added_at: u64,
+    pub output_path: Option<String>,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub watch_folder_id: Option<String>,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueJobEvent<T> {
+    pub job_id: String,
+    #[serde(flatten)]
+    pub payload: T,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobQueue {
+    pub paused: bool,
+    pub jobs: Vec<QueueJob>,
+}
+
+#[cfg(feature = "gui")]
+impl JobQueue {
+    pub fn add(&mut self, video_path: String, overrides: JobOverrides) -> &mut QueueJob {
+        self.jobs.push(QueueJob {
+            id: new_job_id(),
+            video_path,
+            overrides,
+            status: QueueJobStatus::Queued,
+            added_at: unix_timestamp(),
+            output_path: None,
+            error: None,
+            watch_folder_id: None,
+        });
+
+        let last = self.jobs.len() - 1;
+        &mut self.jobs[last]
+    }
+
+    pub fn contains_video(&self, video_path: &str) -> bool {
+        self.jobs.iter().any(|job| job.video_path == video_path)
+    }
+
+    pub fn find_mut(&mut self, job_id: &str) -> Option<&mut QueueJob> {
+        self.jobs.iter_mut().find(|job| job.id == job_id)
+    }
+
+    pub fn remove(&mut self, job_id: &str) -> Option<QueueJob> {
+        let index = self.jobs.iter().position(|job| job.id == job_id)?;
+        Some(self.jobs.remove(index))
+    }
+
+    /// Puts the listed jobs first, in the given order. Jobs that were not listed keep their
+    /// relative order after them, so a job added meanwhile is never dropped.
+    pub fn reorder(&mut self, job_ids: &[String]) -> Result<(), String> {
+        for (index, job_id) in job_ids.iter().enumerate() {
+            if job_ids[..index].contains(job_id) {
+                return Err(format!("Job {job_id} is listed more than once."));
+            }
+            if !self.jobs.iter().any(|job| &job.id == job_id) {
+                return Err(format!("Job {job_id} is not in the queue."));
+            }
+        }
+
+        let mut remaining = std::mem::take(&mut self.jobs);
+        for job_id in job_ids {
+            if let Some(index) = remaining.iter().position(|job| &job.id == job_id) {
+                self.jobs.push(remaining.remove(index));
+            }
+        }
+        self.jobs.append(&mut remaining);
+
+        Ok(())
+    }
+
+    /// Returns the first queued job that is not still winding down from an interruption.
+    pub fn next_ready(&self, running: &HashMap<String, CancellationToken>) -> Option<&QueueJob> {
+        self.jobs
+            .iter()
+            .find(|job| job.status == QueueJobStatus::Queued && !running.contains_key(&job.id))
+    }
+
+    /// Jobs that were running when the app exited go back to the queue; their manifests let
+    /// them pick up from the last completed chunk.
+    pub fn recover_interrupted(&mut self) {
+        for job in &mut self.jobs {
+            if job.status == QueueJobStatus::Running {
+                job.status = QueueJobStatus::Queued;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Default)]
+pub struct QueueRuntime {
+    pub loaded: bool,
+    pub queue: JobQueue,
+    pub running: HashMap<String, CancellationToken>,
+}
+
+#[cfg(feature = "gui")]
+#[derive(Debug, Default)]
+pub struct JobQueueState {
+    pub runtime: Mutex<QueueRuntime>,
+    pub wake: Notify,
+}
+
+#[cfg(all(test, feature = "gui"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use tokio_util::sync::CancellationToken;
+
+    use super::{JobOverrides, JobQueue, QueueJobStatus};
+    use crate::models::settings::{AppSettings, SubtitleFormat};
+
+    fn queue_of(paths: &[&str]) -> JobQueue {
+        let mut queue = JobQueue::default();
+        for path in paths {
+            queue.add(path.to_string(), JobOverrides::default());
+        }
+        queue
+    }
+
+    fn paths(queue: &JobQueue) -> Vec<&str> {
+        queue
+            .jobs
+            .iter()
+            .map(|job| job.video_path.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn overrides_take_precedence_over_settings() {
+        let settings = AppSettings {
+            source_language: "de".to_string(),
+            target_language: Some("en".to_string()),
+            default_output_folder: Some("/subtitles".to_string()),
+            ..AppSettings::default()
+        };
+
+        let defaults = JobOverrides::default().resolve("/videos/a.mp4", &settings);
+        assert_eq!(defaults.source_language, "de");
+        assert_eq!(defaults.target_language.as_deref(), Some("en"));
+        assert_eq!(defaults.output_folder.as_deref(), Some("/subtitles"));
+        assert_eq!(defaults.output_format, Some(SubtitleFormat::Srt));
+
+        let overrides = JobOverrides {
+            source_language: Some("fr".to_string()),
+            target_language: Some("fr".to_string()),
+            output_folder: Some("  ".to_string()),
+            output_format: None,
+            audio_stream_index: Some(2),
+        };
+        let config = overrides.resolve("/videos/a.mp4", &settings);
+        assert_eq!(config.source_language, "fr");
+        assert_eq!(config.target_language.as_deref(), Some("fr"));
+        assert_eq!(config.output_folder.as_deref(), Some("/subtitles"));
+        assert_eq!(config.audio_stream_index, Some(2));
+    }
+
+    #[test]
+    fn reorder_moves_listed_jobs_first() {
+        let mut queue = queue_of(&["a", "b", "c", "d"]);
+        let ids = vec![queue.jobs[2].id.clone(), queue.jobs[0].id.clone()];
+
+        queue.reorder(&ids).expect("known ids");
+        assert_eq!(paths(&queue), ["c", "a", "b", "d"]);
+
+        assert!(queue.reorder(&["job-missing".to_string()]).is_err());
+        assert_eq!(paths(&queue), ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn next_ready_skips_paused_and_winding_down_jobs() {
+        let mut queue = queue_of(&["a", "b", "c"]);
+        queue.jobs[0].status = QueueJobStatus::Paused;
+        let mut running = HashMap::new();
+        running.insert(queue.jobs[1].id.clone(), CancellationToken::new());
+
+        let next = queue.next_ready(&running).expect("a ready job");
+        assert_eq!(next.video_path, "c");
+
+        queue.jobs[2].status = QueueJobStatus::Running;
+        queue.recover_interrupted();
+        assert_eq!(queue.jobs[2].status, QueueJobStatus::Queued);
+    }
+}
@@ -1,5 +1,106 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::style::StylePreset;
+
+pub const DEFAULT_API_BASE_URL: &str = "https://api.openai.com";
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-06-01";
+pub const DEFAULT_COMPATIBLE_MODEL: &str = "whisper-1";
+pub const DEFAULT_TRANSLATION_MODEL: &str = "gpt-4o-mini";
+
+fn default_api_base_url() -> String {
+    DEFAULT_API_BASE_URL.to_string()
+}
+
+fn default_azure_api_version() -> String {
+    DEFAULT_AZURE_API_VERSION.to_string()
+}
+
+fn default_compatible_model() -> String {
+    DEFAULT_COMPATIBLE_MODEL.to_string()
+}
+
+fn default_translation_model() -> String {
+    DEFAULT_TRANSLATION_MODEL.to_string()
+}
+
+fn default_silence_window_seconds() -> u32 {
+    30
+}
+
+fn default_chunk_overlap_seconds() -> u32 {
+    10
+}
+
+fn default_max_concurrent_jobs() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    #[default]
+    OpenAi,
+    Azure,
+    Compatible,
+    WhisperCpp,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChunkingMode {
+    #[default]
+    Fixed,
+    Silence,
+    Overlap,
+}
+
+/// How a transcript reaches the target language.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationMode {
+    /// Translate the finished transcript through the chat-completions endpoint.
+    #[default]
+    Chat,
+    /// Have the provider translate the audio itself. Only English targets use this; other
+    /// targets still go through the chat stage.
+    Audio,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    #[default]
+    Srt,
+    /// Advanced SubStation Alpha, with karaoke timing when word timestamps are available.
+    Ass,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Ass => "ass",
+            Self::Vtt => "vtt",
+        }
+    }
+
+    /// The ffmpeg subtitle encoder that writes this format.
+    #[cfg(feature = "gui")]
+    pub fn encoder(self) -> &'static str {
+        match self {
+            Self::Srt => "srt",
+            Self::Ass => "ass",
+            Self::Vtt => "webvtt",
+        }
+    }
+}
+
+fn is_http_url(value: &str) -> bool {
+    let value = value.trim();
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AppSettings {
@@ -10,6 +111,47 @@ pub struct AppSettings {
     pub max_retries: u8,
     pub source_language: String,
     pub target_language: Option<String>,
+    #[serde(default)]
+    pub provider: ProviderKind,
+    #[serde(default = "default_api_base_url")]
+    pub api_base_url: String,
+    #[serde(default)]
+    pub azure_endpoint: String,
+    #[serde(default)]
+    pub azure_deployment: String,
+    #[serde(default = "default_azure_api_version")]
+    pub azure_api_version: String,
+    #[serde(default = "default_compatible_model")]
+    pub compatible_model: String,
+    #[serde(default)]
+    pub compatible_price_per_minute_usd: f64,
+    #[serde(default)]
+    pub whisper_cpp_model_path: Option<String>,
+    /// Chat-completions endpoint used to translate finished transcripts.
+    #[serde(default = "default_api_base_url")]
+    pub translation_api_base_url: String,
+    #[serde(default = "default_translation_model")]
+    pub translation_model: String,
+    /// Falls back to `api_key` when empty.
+    #[serde(default)]
+    pub translation_api_key: String,
+    #[serde(default)]
+    pub translation_mode: TranslationMode,
+    #[serde(default)]
+    pub chunking_mode: ChunkingMode,
+    #[serde(default = "default_silence_window_seconds")]
+    pub silence_window_seconds: u32,
+    #[serde(default = "default_chunk_overlap_seconds")]
+    pub chunk_overlap_seconds: u32,
+    #[serde(default)]
+    pub default_output_format: SubtitleFormat,
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: u32,
+    #[serde(default)]
+    pub style_presets: Vec<StylePreset>,
+    /// Preset used for ASS output; the built-in default style when unset.
+    #[serde(default)]
+    pub active_style_preset: Option<String>,
 }
 
 impl Default for AppSettings {
@@ -22,11 +164,42 @@ impl Default for AppSettings {
             max_retries: 3,
             source_language: "en".to_string(),
             target_language: None,
+            provider: ProviderKind::default(),
+            api_base_url: default_api_base_url(),
+            azure_endpoint: String::new(),
+            azure_deployment: String::new(),
+            azure_api_version: default_azure_api_version(),
+            compatible_model: default_compatible_model(),
+            compatible_price_per_minute_usd: 0.0,
+            whisper_cpp_model_path: None,
+            translation_api_base_url: default_api_base_url(),
+            translation_model: default_translation_model(),
+            translation_api_key: String::new(),
+            translation_mode: TranslationMode::default(),
+            chunking_mode: ChunkingMode::default(),
+            silence_window_seconds: default_silence_window_seconds(),
+            chunk_overlap_seconds: default_chunk_overlap_seconds(),
+            default_output_format: SubtitleFormat::default(),
+            max_concurrent_jobs: default_max_concurrent_jobs(),
+            style_presets: Vec::new(),
+            active_style_preset: None,
         }
     }
 }
 
 impl AppSettings {
+    pub fn active_style_preset(&self) -> StylePreset {
+        self.active_style_preset
+            .as_deref()
+            .and_then(|active| {
+                self.style_presets
+                    .iter()
+                    .find(|preset| preset.name.trim() == active.trim())
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         if !(5..=60).contains(&self.chunk_duration_minutes) {
             return Err("Chunk duration must be between 5 and 60 minutes.".to_string());
@@ -36,10 +209,94 @@ impl AppSettings {
             return Err("Max retries must be between 1 and 10.".to_string());
         }
 
+        if !(5..=120).contains(&self.silence_window_seconds) {
+            return Err("Silence search window must be between 5 and 120 seconds.".to_string());
+        }
+
+        if !(1..=60).contains(&self.chunk_overlap_seconds) {
+            return Err("Chunk overlap must be between 1 and 60 seconds.".to_string());
+        }
+
+        if !(1..=4).contains(&self.max_concurrent_jobs) {
+            return Err("Concurrent jobs must be between 1 and 4.".to_string());
+        }
+
         if self.source_language.trim().is_empty() {
             return Err("Source language is required.".to_string());
         }
 
+        if !is_http_url(&self.api_base_url) {
+            return Err("API base URL must start with http:// or https://.".to_string());
+        }
+
+        if !is_http_url(&self.translation_api_base_url) {
+            return Err(
+                "Translation API base URL must start with http:// or https://.".to_string(),
+            );
+        }
+
+        if self.translation_model.trim().is_empty() {
+            return Err("Translation model is required.".to_string());
+        }
+
+        for (index, preset) in self.style_presets.iter().enumerate() {
+            preset.validate()?;
+            if self.style_presets[..index]
+                .iter()
+                .any(|other| other.name.trim() == preset.name.trim())
+            {
+                return Err(format!(
+                    "Style preset \"{}\" exists twice.",
+                    preset.name.trim()
+                ));
+            }
+        }
+        if let Some(active) = &self.active_style_preset {
+            if !self
+                .style_presets
+                .iter()
+                .any(|preset| preset.name.trim() == active.trim())
+            {
+                return Err(format!("Style preset \"{active}\" does not exist."));
+            }
+        }
+
+        match self.provider {
+            ProviderKind::OpenAi => {}
+            ProviderKind::Azure => {
+                if !is_http_url(&self.azure_endpoint) {
+                    return Err("Azure endpoint must start with http:// or https://.".to_string());
+                }
+                if self.azure_deployment.trim().is_empty() {
+                    return Err("Azure deployment name is required.".to_string());
+                }
+                if self.azure_api_version.trim().is_empty() {
+                    return Err("Azure API version is required.".to_string());
+                }
+            }
+            ProviderKind::Compatible => {
+                if self.compatible_model.trim().is_empty() {
+                    return Err("Model name is required for OpenAI-compatible servers.".to_string());
+                }
+                if !self.compatible_price_per_minute_usd.is_finite()
+                    || self.compatible_price_per_minute_usd < 0.0
+                {
+                    return Err("Price per minute must be zero or a positive amount.".to_string());
+                }
+            }
+            ProviderKind::WhisperCpp => {
+                let has_model = self
+                    .whisper_cpp_model_path
+                    .as_deref()
+                    .is_some_and(|path| !path.trim().is_empty());
+                if !has_model {
+                    return Err(
+                        "Select a whisper.cpp model file for offline transcription.".to_string()
+                    );
+                }
+            }
+        }
+
         Ok(())
     }
 }
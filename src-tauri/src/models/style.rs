@@ -0,0 +1,341 @@
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_FONT_NAME: &str = "Arial";
+pub const DEFAULT_FONT_SIZE: u32 = 22;
+pub const DEFAULT_PRESET_NAME: &str = "Default";
+pub const MAX_FONT_SIZE: u32 = 200;
+pub const MAX_OUTLINE_WIDTH: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitlePosition {
+    Top,
+    Middle,
+    #[default]
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HorizontalAlignment {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// How subtitles look when rendered by libass. Sizes and margins are in script pixels,
+/// which for SRT input means a 384x288 canvas scaled to the video.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SubtitleStyle {
+    pub font_name: String,
+    pub font_size: u32,
+    pub bold: bool,
+    pub italic: bool,
+    /// `#RRGGBB`.
+    pub primary_color: String,
+    /// `#RRGGBB`, the colour karaoke words change to once spoken.
+    pub highlight_color: String,
+    /// `#RRGGBB`.
+    pub outline_color: String,
+    pub outline_width: f32,
+    pub position: SubtitlePosition,
+    pub alignment: HorizontalAlignment,
+    pub margin_vertical: u32,
+    pub margin_horizontal: u32,
+}
+
+impl Default for SubtitleStyle {
+    fn default() -> Self {
+        Self {
+            font_name: DEFAULT_FONT_NAME.to_string(),
+            font_size: DEFAULT_FONT_SIZE,
+            bold: false,
+            italic: false,
+            primary_color: "#FFFFFF".to_string(),
+            highlight_color: "#FFFF00".to_string(),
+            outline_color: "#000000".to_string(),
+            outline_width: 2.0,
+            position: SubtitlePosition::Bottom,
+            alignment: HorizontalAlignment::Center,
+            margin_vertical: 16,
+            margin_horizontal: 10,
+        }
+    }
+}
+
+/// A style that replaces the preset's base style for one language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageStyle {
+    pub language: String,
+    pub style: SubtitleStyle,
+}
+
+/// A style for cues that start with `<speaker>:`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakerStyle {
+    pub speaker: String,
+    pub style: SubtitleStyle,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StylePreset {
+    pub name: String,
+    pub style: SubtitleStyle,
+    #[serde(default)]
+    pub language_styles: Vec<LanguageStyle>,
+    #[serde(default)]
+    pub speaker_styles: Vec<SpeakerStyle>,
+}
+
+impl Default for StylePreset {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_PRESET_NAME.to_string(),
+            style: SubtitleStyle::default(),
+            language_styles: Vec::new(),
+            speaker_styles: Vec::new(),
+        }
+    }
+}
+
+/// Converts `#RRGGBB` to the `&HAABBGGRR` form ASS uses, fully opaque.
+pub fn ass_color(hex: &str) -> Option<String> {
+    let hex = hex.trim().strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let (red, green, blue) = (&hex[0..2], &hex[2..4], &hex[4..6]);
+    Some(format!("&H00{blue}{green}{red}").to_ascii_uppercase())
+}
+
+// Names end up inside ASS style lines and filter argument lists.
+fn has_reserved_characters(value: &str) -> bool {
+    value.contains([',', ':', '\'', '\\'])
+}
+
+impl SubtitleStyle {
+    pub fn validate(&self) -> Result<(), String> {
+        let font_name = self.font_name.trim();
+        if font_name.is_empty() {
+            return Err("Subtitle font name cannot be empty.".to_string());
+        }
+        if has_reserved_characters(font_name) {
+            return Err("Subtitle font name cannot contain , : ' or \\.".to_string());
+        }
+        if self.font_size == 0 || self.font_size > MAX_FONT_SIZE {
+            return Err(format!(
+                "Subtitle font size must be between 1 and {MAX_FONT_SIZE}."
+            ));
+        }
+        if !(0.0..=MAX_OUTLINE_WIDTH).contains(&self.outline_width) {
+            return Err(format!(
+                "Subtitle outline width must be between 0 and {MAX_OUTLINE_WIDTH}."
+            ));
+        }
+        for (label, color) in [
+            ("text", &self.primary_color),
+            ("highlight", &self.highlight_color),
+            ("outline", &self.outline_color),
+        ] {
+            if ass_color(color).is_none() {
+                return Err(format!(
+                    "Subtitle {label} color must look like #RRGGBB, got \"{color}\"."
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// ASS numpad alignment: 1-3 along the bottom, 4-6 in the middle, 7-9 at the top.
+    pub fn ass_alignment(&self) -> u8 {
+        let row = match self.position {
+            SubtitlePosition::Bottom => 0,
+            SubtitlePosition::Middle => 3,
+            SubtitlePosition::Top => 6,
+        };
+        let column = match self.alignment {
+            HorizontalAlignment::Left => 1,
+            HorizontalAlignment::Center => 2,
+            HorizontalAlignment::Right => 3,
+        };
+
+        row + column
+    }
+
+    /// The style as a libass `force_style` override list.
+    #[cfg_attr(not(feature = "gui"), allow(dead_code))]
+    pub fn force_style(&self) -> String {
+        let color = |hex: &str| ass_color(hex).unwrap_or_else(|| "&H00FFFFFF".to_string());
+        let flag = |enabled: bool| if enabled { -1 } else { 0 };
+
+        format!(
+            "FontName={},FontSize={},Bold={},Italic={},PrimaryColour={},OutlineColour={},BorderStyle=1,Outline={},Shadow=0,Alignment={},MarginL={},MarginR={},MarginV={}",
+            self.font_name.trim(),
+            self.font_size,
+            flag(self.bold),
+            flag(self.italic),
+            color(&self.primary_color),
+            color(&self.outline_color),
+            self.outline_width,
+            self.ass_alignment(),
+            self.margin_horizontal,
+            self.margin_horizontal,
+            self.margin_vertical
+        )
+    }
+}
+
+impl StylePreset {
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Style preset name cannot be empty.".to_string());
+        }
+        let context = |error: String| format!("Style preset \"{name}\": {error}");
+        self.style.validate().map_err(context)?;
+
+        for (index, language_style) in self.language_styles.iter().enumerate() {
+            let language = language_style.language.trim();
+            if language.is_empty() {
+                return Err(context("language styles need a language.".to_string()));
+            }
+            if self.language_styles[..index]
+                .iter()
+                .any(|other| other.language.trim() == language)
+            {
+                return Err(context(format!("language \"{language}\" is styled twice.")));
+            }
+            language_style.style.validate().map_err(context)?;
+        }
+
+        for (index, speaker_style) in self.speaker_styles.iter().enumerate() {
+            let speaker = speaker_style.speaker.trim();
+            if speaker.is_empty() || has_reserved_characters(speaker) {
+                return Err(context(
+                    "speaker names cannot be empty or contain , : ' or \\.".to_string(),
+                ));
+            }
+            if self.speaker_styles[..index]
+                .iter()
+                .any(|other| other.speaker.trim().eq_ignore_ascii_case(speaker))
+            {
+                return Err(context(format!("speaker \"{speaker}\" is styled twice.")));
+            }
+            speaker_style.style.validate().map_err(context)?;
+        }
+
+        Ok(())
+    }
+
+    /// The base style, or the language's own style when the preset has one.
+    pub fn style_for_language(&self, language: Option<&str>) -> &SubtitleStyle {
+        language
+            .and_then(|language| {
+                self.language_styles
+                    .iter()
+                    .find(|language_style| language_style.language.trim() == language)
+            })
+            .map(|language_style| &language_style.style)
+            .unwrap_or(&self.style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ass_color, HorizontalAlignment, LanguageStyle, SpeakerStyle, StylePreset, SubtitlePosition,
+        SubtitleStyle,
+    };
+
+    #[test]
+    fn converts_hex_colors_to_ass_order() {
+        assert_eq!(ass_color("#FFCC00").as_deref(), Some("&H0000CCFF"));
+        assert_eq!(ass_color("#1a2b3c").as_deref(), Some("&H003C2B1A"));
+        assert_eq!(ass_color("FFCC00"), None);
+        assert_eq!(ass_color("#FFF"), None);
+    }
+
+    #[test]
+    fn builds_force_style_and_rejects_bad_values() {
+        let style = SubtitleStyle {
+            position: SubtitlePosition::Top,
+            primary_color: "#FFFF00".to_string(),
+            ..SubtitleStyle::default()
+        };
+        assert!(style.validate().is_ok());
+        let force_style = style.force_style();
+        assert!(force_style
+            .starts_with("FontName=Arial,FontSize=22,Bold=0,Italic=0,PrimaryColour=&H0000FFFF,"));
+        assert!(force_style.contains("Alignment=8"));
+
+        let bad_font = SubtitleStyle {
+            font_name: "Arial,Bold".to_string(),
+            ..SubtitleStyle::default()
+        };
+        assert!(bad_font.validate().is_err());
+
+        let bad_color = SubtitleStyle {
+            outline_color: "black".to_string(),
+            ..SubtitleStyle::default()
+        };
+        assert!(bad_color.validate().is_err());
+    }
+
+    #[test]
+    fn maps_position_and_alignment_to_numpad_codes() {
+        let style = |position, alignment| SubtitleStyle {
+            position,
+            alignment,
+            ..SubtitleStyle::default()
+        };
+
+        assert_eq!(
+            style(SubtitlePosition::Bottom, HorizontalAlignment::Left).ass_alignment(),
+            1
+        );
+        assert_eq!(
+            style(SubtitlePosition::Middle, HorizontalAlignment::Center).ass_alignment(),
+            5
+        );
+        assert_eq!(
+            style(SubtitlePosition::Top, HorizontalAlignment::Right).ass_alignment(),
+            9
+        );
+    }
+
+    #[test]
+    fn picks_language_styles_and_rejects_duplicate_overrides() {
+        let german = SubtitleStyle {
+            font_size: 30,
+            ..SubtitleStyle::default()
+        };
+        let mut preset = StylePreset {
+            language_styles: vec![LanguageStyle {
+                language: "de".to_string(),
+                style: german.clone(),
+            }],
+            speaker_styles: vec![SpeakerStyle {
+                speaker: "Anna".to_string(),
+                style: SubtitleStyle::default(),
+            }],
+            ..StylePreset::default()
+        };
+        assert!(preset.validate().is_ok());
+        assert_eq!(preset.style_for_language(Some("de")), &german);
+        assert_eq!(preset.style_for_language(Some("fr")), &preset.style);
+        assert_eq!(preset.style_for_language(None), &preset.style);
+
+        preset.speaker_styles.push(SpeakerStyle {
+            speaker: "anna".to_string(),
+            style: SubtitleStyle::default(),
+        });
+        assert!(preset.validate().is_err());
+    }
+}
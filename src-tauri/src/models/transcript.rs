@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    /// Per-word timing, only filled in by providers that return word timestamps.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<Word>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Word {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+/// Hands each word to the segment its midpoint falls in. Both lists must be in time order;
+/// words before the first or after the last segment go to that segment.
+pub fn attach_words(segments: &mut [Segment], words: Vec<Word>) {
+    let mut current = 0;
+
+    for word in words {
+        let midpoint = (word.start + word.end) / 2.0;
+        while current + 1 < segments.len() && midpoint >= segments[current].end {
+            current += 1;
+        }
+        if let Some(segment) = segments.get_mut(current) {
+            segment.words.push(word);
+        }
+    }
+}
+
+/// Moves chunk-relative word timings onto the video timeline, clamped to the segment.
+pub fn offset_words(words: &[Word], offset: f64, start: f64, end: f64) -> Vec<Word> {
+    words
+        .iter()
+        .filter(|word| word.start.is_finite() && word.end.is_finite())
+        .map(|word| {
+            let word_start = (word.start + offset).clamp(start, end);
+            Word {
+                start: word_start,
+                end: (word.end + offset).clamp(word_start, end),
+                text: word.text.clone(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attach_words, offset_words, Segment, Word};
+
+    fn word(start: f64, end: f64, text: &str) -> Word {
+        Word {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn attaches_words_to_the_segment_they_are_spoken_in() {
+        let mut segments = vec![
+            Segment {
+                start: 0.0,
+                end: 2.0,
+                text: "Hello there.".to_string(),
+                words: Vec::new(),
+            },
+            Segment {
+                start: 2.0,
+                end: 5.0,
+                text: "General Kenobi.".to_string(),
+                words: Vec::new(),
+            },
+        ];
+        attach_words(
+            &mut segments,
+            vec![
+                word(0.2, 0.8, "Hello"),
+                word(0.9, 2.1, "there"),
+                word(2.4, 3.0, "General"),
+                word(3.1, 6.0, "Kenobi"),
+            ],
+        );
+
+        assert_eq!(segments[0].words.len(), 2);
+        assert_eq!(segments[1].words[1].text, "Kenobi");
+    }
+
+    #[test]
+    fn offsets_and_clamps_words_to_their_segment() {
+        let words = offset_words(
+            &[word(0.5, 1.0, "a"), word(2.0, 9.0, "b")],
+            10.0,
+            10.6,
+            15.0,
+        );
+
+        assert_eq!(words[0].start, 10.6);
+        assert_eq!(words[0].end, 11.0);
+        assert_eq!(words[1].start, 12.0);
+        assert_eq!(words[1].end, 15.0);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::models::job::unix_timestamp;
+use crate::models::queue::JobOverrides;
+
+pub const WATCH_FOLDERS_UPDATED_EVENT: &str = "watch-folders-updated";
+
+pub fn new_watch_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+
+    format!("watch-{millis}-{:04x}", fastrand::u16(..))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFolder {
+    pub id: String,
+    pub path: String,
+    pub enabled: bool,
+    pub overrides: JobOverrides,
+    /// Processed videos are moved here once their transcript is written.
+    pub archive_folder: Option<String>,
+    /// Names of the files that were already in the folder when it was registered and should
+    /// be left alone.
+    #[serde(default)]
+    pub existing_files: Vec<String>,
+    pub added_at: u64,
+}
+
+impl WatchFolder {
+    pub fn new(
+        path: String,
+        overrides: JobOverrides,
+        archive_folder: Option<String>,
+        existing_files: Vec<String>,
+    ) -> Self {
+        Self {
+            id: new_watch_id(),
+            path,
+            enabled: true,
+            overrides,
+            archive_folder,
+            existing_files,
+            added_at: unix_timestamp(),
+        }
+    }
+
+    pub fn was_present_at_registration(&self, path: &Path) -> bool {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| self.existing_files.iter().any(|existing| existing == name))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileSnapshot {
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+/// Tracks files between scans so that a file is handed over only once it has stopped
+/// growing, and only once per session.
+#[derive(Debug, Default)]
+pub struct GrowthTracker {
+    last_seen: HashMap<PathBuf, FileSnapshot>,
+    settled: HashSet<PathBuf>,
+}
+
+impl GrowthTracker {
+    /// Returns true the first time a file is seen unchanged across two consecutive scans.
+    pub fn observe(&mut self, path: &Path, snapshot: FileSnapshot) -> bool {
+        if self.settled.contains(path) {
+            return false;
+        }
+
+        match self.last_seen.insert(path.to_path_buf(), snapshot) {
+            Some(previous) if previous == snapshot && snapshot.size > 0 => {
+                self.last_seen.remove(path);
+                self.settled.insert(path.to_path_buf());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drops files that disappeared, so a new file reusing the name is considered afresh.
+    pub fn retain_present(&mut self, present: &HashSet<PathBuf>) {
+        self.last_seen.retain(|path, _| present.contains(path));
+        self.settled.retain(|path| present.contains(path));
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WatchRuntime {
+    pub loaded: bool,
+    pub folders: Vec<WatchFolder>,
+    pub tracker: GrowthTracker,
+}
+
+#[derive(Debug, Default)]
+pub struct WatchFolderState {
+    pub runtime: Mutex<WatchRuntime>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{FileSnapshot, GrowthTracker, WatchFolder};
+    use crate::models::queue::JobOverrides;
+
+    fn snapshot(size: u64, modified_secs: u64) -> FileSnapshot {
+        FileSnapshot {
+            size,
+            modified: Some(UNIX_EPOCH + Duration::from_secs(modified_secs)),
+        }
+    }
+
+    #[test]
+    fn settles_once_a_file_stops_growing() {
+        let mut tracker = GrowthTracker::default();
+        let path = Path::new("/drop/lecture.mp4");
+
+        assert!(!tracker.observe(path, snapshot(100, 1)));
+        assert!(!tracker.observe(path, snapshot(250, 2)));
+        assert!(tracker.observe(path, snapshot(250, 2)));
+        assert!(!tracker.observe(path, snapshot(250, 2)));
+    }
+
+    #[test]
+    fn ignores_empty_files_and_forgets_removed_ones() {
+        let mut tracker = GrowthTracker::default();
+        let path = Path::new("/drop/lecture.mp4");
+
+        assert!(!tracker.observe(path, snapshot(0, 1)));
+        assert!(!tracker.observe(path, snapshot(0, 1)));
+        assert!(!tracker.observe(path, snapshot(10, 2)));
+        assert!(tracker.observe(path, snapshot(10, 2)));
+
+        tracker.retain_present(&HashSet::<PathBuf>::new());
+        assert!(!tracker.observe(path, snapshot(10, 3)));
+        assert!(tracker.observe(path, snapshot(10, 3)));
+    }
+
+    #[test]
+    fn recognises_files_present_at_registration() {
+        let folder = WatchFolder::new(
+            "/drop".to_string(),
+            JobOverrides::default(),
+            None,
+            vec!["old.mp4".to_string()],
+        );
+
+        assert!(folder.was_present_at_registration(Path::new("/drop/old.mp4")));
+        assert!(!folder.was_present_at_registration(Path::new("/drop/new.mp4")));
+    }
+}
@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::models::settings::AppSettings;
+use crate::providers::whisper::{
+    Authorization, ResponseFormat, Transcription, WhisperClient, WhisperError, MAX_UPLOAD_BYTES,
+};
+use crate::providers::{ProviderCapabilities, TranscriptionProvider};
+
+const PRICE_PER_MINUTE_USD: f64 = 0.006;
+
+pub fn capabilities() -> ProviderCapabilities {
+    ProviderCapabilities {
+        max_upload_bytes: MAX_UPLOAD_BYTES,
+        audio_format: "mp3",
+        response_formats: vec![ResponseFormat::Text, ResponseFormat::VerboseJson],
+        word_timestamps: true,
+        translation: true,
+        requires_api_key: true,
+        price_per_minute_usd: PRICE_PER_MINUTE_USD,
+    }
+}
+
+fn deployment_url(settings: &AppSettings, operation: &str) -> String {
+    format!(
+        "{}/openai/deployments/{}/audio/{operation}?api-version={}",
+        settings.azure_endpoint.trim().trim_end_matches('/'),
+        settings.azure_deployment.trim(),
+        settings.azure_api_version.trim()
+    )
+}
+
+pub struct AzureOpenAiProvider {
+    client: WhisperClient,
+}
+
+impl AzureOpenAiProvider {
+    pub fn new(settings: &AppSettings) -> Result<Self, WhisperError> {
+        let client = WhisperClient::with_endpoints(
+            deployment_url(settings, "transcriptions"),
+            deployment_url(settings, "translations"),
+            Authorization::ApiKeyHeader(settings.api_key.trim().to_string()),
+            None,
+        )?
+        .with_word_timestamps();
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for AzureOpenAiProvider {
+    fn name(&self) -> &'static str {
+        "Azure OpenAI"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        capabilities()
+    }
+
+    async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.transcribe(audio_path, language, format).await
+    }
+
+    async fn translate(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.translate(audio_path, format).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deployment_url;
+    use crate::models::settings::{AppSettings, ProviderKind};
+
+    #[test]
+    fn builds_deployment_scoped_urls() {
+        let settings = AppSettings {
+            provider: ProviderKind::Azure,
+            azure_endpoint: "https://contoso.openai.azure.com/".to_string(),
+            azure_deployment: "whisper-prod".to_string(),
+            ..AppSettings::default()
+        };
+
+        assert_eq!(
+            deployment_url(&settings, "transcriptions"),
+            "https://contoso.openai.azure.com/openai/deployments/whisper-prod/audio/transcriptions?api-version=2024-06-01"
+        );
+    }
+}
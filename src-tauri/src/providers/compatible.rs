@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::models::settings::AppSettings;
+use crate::providers::whisper::{
+    Authorization, ResponseFormat, Transcription, WhisperClient, WhisperError, MAX_UPLOAD_BYTES,
+};
+use crate::providers::{ProviderCapabilities, TranscriptionProvider};
+
+const TRANSCRIPTIONS_PATH: &str = "/v1/audio/transcriptions";
+const TRANSLATIONS_PATH: &str = "/v1/audio/translations";
+
+// Self-hosted servers vary widely, so only advertise what the OpenAI wire format guarantees.
+pub fn capabilities(settings: &AppSettings) -> ProviderCapabilities {
+    ProviderCapabilities {
+        max_upload_bytes: MAX_UPLOAD_BYTES,
+        audio_format: "mp3",
+        response_formats: vec![ResponseFormat::Text, ResponseFormat::VerboseJson],
+        word_timestamps: false,
+        translation: true,
+        requires_api_key: false,
+        price_per_minute_usd: settings.compatible_price_per_minute_usd,
+    }
+}
+
+pub struct CompatibleProvider {
+    client: WhisperClient,
+    capabilities: ProviderCapabilities,
+}
+
+impl CompatibleProvider {
+    pub fn new(settings: &AppSettings) -> Result<Self, WhisperError> {
+        let base_url = settings.api_base_url.trim().trim_end_matches('/');
+        let client = WhisperClient::with_endpoints(
+            format!("{base_url}{TRANSCRIPTIONS_PATH}"),
+            format!("{base_url}{TRANSLATIONS_PATH}"),
+            Authorization::bearer(&settings.api_key),
+            Some(settings.compatible_model.trim().to_string()),
+        )?;
+
+        Ok(Self {
+            client,
+            capabilities: capabilities(settings),
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for CompatibleProvider {
+    fn name(&self) -> &'static str {
+        "OpenAI-compatible server"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.capabilities.clone()
+    }
+
+    async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.transcribe(audio_path, language, format).await
+    }
+
+    async fn translate(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.translate(audio_path, format).await
+    }
+}
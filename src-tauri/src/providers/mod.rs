@@ -0,0 +1,78 @@
+pub mod azure;
+pub mod compatible;
+pub mod openai;
+pub mod translation;
+pub mod whisper;
+pub mod whisper_cpp;
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::models::settings::{AppSettings, ProviderKind};
+use crate::providers::whisper::{ResponseFormat, Transcription, WhisperError};
+use crate::utils::ffmpeg::Sidecars;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderCapabilities {
+    pub max_upload_bytes: u64,
+    pub audio_format: &'static str,
+    pub response_formats: Vec<ResponseFormat>,
+    pub word_timestamps: bool,
+    pub translation: bool,
+    pub requires_api_key: bool,
+    pub price_per_minute_usd: f64,
+}
+
+#[async_trait]
+pub trait TranscriptionProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError>;
+
+    async fn translate(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError>;
+}
+
+pub fn provider_from_settings(
+    sidecars: &Sidecars,
+    settings: &AppSettings,
+) -> Result<Box<dyn TranscriptionProvider>, WhisperError> {
+    Ok(match settings.provider {
+        ProviderKind::OpenAi => Box::new(openai::OpenAiProvider::new(settings)?),
+        ProviderKind::Azure => Box::new(azure::AzureOpenAiProvider::new(settings)?),
+        ProviderKind::Compatible => Box::new(compatible::CompatibleProvider::new(settings)?),
+        ProviderKind::WhisperCpp => {
+            Box::new(whisper_cpp::WhisperCppProvider::new(sidecars, settings)?)
+        }
+    })
+}
+
+pub fn capabilities_for(settings: &AppSettings) -> ProviderCapabilities {
+    match settings.provider {
+        ProviderKind::OpenAi => openai::capabilities(),
+        ProviderKind::Azure => azure::capabilities(),
+        ProviderKind::Compatible => compatible::capabilities(settings),
+        ProviderKind::WhisperCpp => whisper_cpp::capabilities(),
+    }
+}
+
+pub fn ensure_credentials(settings: &AppSettings) -> Result<(), String> {
+    if capabilities_for(settings).requires_api_key && settings.api_key.trim().is_empty() {
+        return Err("API key is not configured. Add it in Settings.".to_string());
+    }
+
+    Ok(())
+}
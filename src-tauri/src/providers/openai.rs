@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::models::settings::AppSettings;
+use crate::providers::whisper::{
+    ResponseFormat, Transcription, WhisperClient, WhisperError, MAX_UPLOAD_BYTES,
+};
+use crate::providers::{ProviderCapabilities, TranscriptionProvider};
+
+const PRICE_PER_MINUTE_USD: f64 = 0.006;
+
+pub fn capabilities() -> ProviderCapabilities {
+    ProviderCapabilities {
+        max_upload_bytes: MAX_UPLOAD_BYTES,
+        audio_format: "mp3",
+        response_formats: vec![ResponseFormat::Text, ResponseFormat::VerboseJson],
+        word_timestamps: true,
+        translation: true,
+        requires_api_key: true,
+        price_per_minute_usd: PRICE_PER_MINUTE_USD,
+    }
+}
+
+pub struct OpenAiProvider {
+    client: WhisperClient,
+}
+
+impl OpenAiProvider {
+    pub fn new(settings: &AppSettings) -> Result<Self, WhisperError> {
+        Ok(Self {
+            client: WhisperClient::new(&settings.api_base_url, &settings.api_key)?
+                .with_word_timestamps(),
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAiProvider {
+    fn name(&self) -> &'static str {
+        "OpenAI"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        capabilities()
+    }
+
+    async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.transcribe(audio_path, language, format).await
+    }
+
+    async fn translate(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.client.translate(audio_path, format).await
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::models::settings::{AppSettings, DEFAULT_API_BASE_URL};
+use crate::models::transcript::Segment;
+use crate::providers::whisper::{parse_error_message, parse_retry_after, Authorization};
+use crate::utils::retry::RetryableError;
+
+const CHAT_COMPLETIONS_PATH: &str = "/v1/chat/completions";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+const MAX_BATCH_SEGMENTS: usize = 40;
+const MAX_BATCH_CHARS: usize = 6_000;
+
+#[derive(Debug)]
+pub enum TranslationError {
+    Network(reqwest::Error),
+    Api {
+        status: u16,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    InvalidResponse(String),
+}
+
+impl fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(source) => write!(f, "Translation request failed: {source}"),
+            Self::Api {
+                status, message, ..
+            } => write!(f, "Translation API returned status {status}: {message}"),
+            Self::InvalidResponse(message) => {
+                write!(f, "Failed to parse translation response: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TranslationError {}
+
+impl RetryableError for TranslationError {
+    // Language models occasionally drop or mangle an entry, so a malformed reply is worth
+    // asking for again.
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(source) => !source.is_builder(),
+            Self::Api { status, .. } => *status == 408 || *status == 429 || *status >= 500,
+            Self::InvalidResponse(_) => true,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// A subtitle cue as exchanged with the model. `id` is the cue's 1-based position in the
+/// transcript, i.e. its SRT sequence number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranslationItem {
+    pub id: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionBody {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatMessage {
+    content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranslatedBatch {
+    segments: Vec<TranslationItem>,
+}
+
+pub fn translation_api_key(settings: &AppSettings) -> &str {
+    match settings.translation_api_key.trim() {
+        "" => settings.api_key.trim(),
+        key => key,
+    }
+}
+
+pub fn ensure_credentials(settings: &AppSettings) -> Result<(), String> {
+    let base_url = settings
+        .translation_api_base_url
+        .trim()
+        .trim_end_matches('/');
+    if base_url == DEFAULT_API_BASE_URL && translation_api_key(settings).is_empty() {
+        return Err(
+            "An API key is required to translate subtitles. Add it in Settings.".to_string(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Splits the transcript into consecutive batches small enough for one request each.
+pub fn batch_ranges(segments: &[Segment]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut chars = 0;
+
+    for (index, segment) in segments.iter().enumerate() {
+        let len = segment.text.chars().count();
+        if index > start && (index - start >= MAX_BATCH_SEGMENTS || chars + len > MAX_BATCH_CHARS) {
+            ranges.push(start..index);
+            start = index;
+            chars = 0;
+        }
+        chars += len;
+    }
+
+    if start < segments.len() {
+        ranges.push(start..segments.len());
+    }
+
+    ranges
+}
+
+fn system_prompt(source_language: &str, target_language: &str) -> String {
+    format!(
+        "You translate video subtitles from {source_language} to {target_language}. \
+         The user sends a JSON object with a \"segments\" array of {{\"id\", \"text\"}} cues. \
+         Reply with only a JSON object of the same shape containing every id exactly once, \
+         with \"text\" translated into {target_language}. Translate each cue on its own: \
+         never merge, split, reorder or drop cues, and keep line breaks inside a cue."
+    )
+}
+
+pub fn request_body(
+    model: &str,
+    source_language: &str,
+    target_language: &str,
+    items: &[TranslationItem],
+) -> serde_json::Value {
+    json!({
+        "model": model,
+        "response_format": { "type": "json_object" },
+        "messages": [
+            { "role": "system", "content": system_prompt(source_language, target_language) },
+            { "role": "user", "content": json!({ "segments": items }).to_string() },
+        ],
+    })
+}
+
+/// Extracts the translations from a chat completion, in the order of `items`. Every id
+/// that was sent must come back.
+pub fn parse_translations(
+    body: &str,
+    items: &[TranslationItem],
+) -> Result<Vec<String>, TranslationError> {
+    let completion: ChatCompletionBody = serde_json::from_str(body)
+        .map_err(|error| TranslationError::InvalidResponse(error.to_string()))?;
+    let content = completion
+        .choices
+        .into_iter()
+        .next()
+        .and_then(|choice| choice.message.content)
+        .ok_or_else(|| TranslationError::InvalidResponse("empty completion".to_string()))?;
+
+    // Tolerate models that wrap the JSON in a code fence or add a sentence around it.
+    let json = match (content.find('{'), content.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &content[start..=end],
+        _ => content.as_str(),
+    };
+    let batch: TranslatedBatch = serde_json::from_str(json)
+        .map_err(|error| TranslationError::InvalidResponse(error.to_string()))?;
+    let mut translated: HashMap<usize, String> = batch
+        .segments
+        .into_iter()
+        .map(|item| (item.id, item.text.trim().to_string()))
+        .collect();
+
+    items
+        .iter()
+        .map(|item| {
+            translated.remove(&item.id).ok_or_else(|| {
+                TranslationError::InvalidResponse(format!("segment {} is missing", item.id))
+            })
+        })
+        .collect()
+}
+
+pub struct TranslationClient {
+    http: reqwest::Client,
+    url: String,
+    authorization: Authorization,
+    model: String,
+}
+
+impl TranslationClient {
+    pub fn new(settings: &AppSettings) -> Result<Self, TranslationError> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(TranslationError::Network)?;
+        let base_url = settings
+            .translation_api_base_url
+            .trim()
+            .trim_end_matches('/');
+
+        Ok(Self {
+            http,
+            url: format!("{base_url}{CHAT_COMPLETIONS_PATH}"),
+            authorization: Authorization::bearer(translation_api_key(settings)),
+            model: settings.translation_model.trim().to_string(),
+        })
+    }
+
+    pub async fn translate(
+        &self,
+        source_language: &str,
+        target_language: &str,
+        items: &[TranslationItem],
+    ) -> Result<Vec<String>, TranslationError> {
+        eprintln!("[translation] POST {} ({} segments)", self.url, items.len());
+
+        let body = request_body(&self.model, source_language, target_language, items);
+        let request = self.http.post(&self.url).json(&body);
+        let request = match &self.authorization {
+            Authorization::None => request,
+            Authorization::Bearer(key) => request.bearer_auth(key),
+            Authorization::ApiKeyHeader(key) => request.header("api-key", key),
+        };
+
+        let response = request.send().await.map_err(TranslationError::Network)?;
+        let status = response.status();
+        let retry_after = parse_retry_after(response.headers());
+        let body = response.text().await.map_err(TranslationError::Network)?;
+
+        if !status.is_success() {
+            return Err(TranslationError::Api {
+                status: status.as_u16(),
+                message: parse_error_message(&body),
+                retry_after,
+            });
+        }
+
+        parse_translations(&body, items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{batch_ranges, parse_translations, TranslationError, TranslationItem};
+    use crate::models::transcript::Segment;
+
+    fn completion(content: &str) -> String {
+        json!({ "choices": [{ "message": { "role": "assistant", "content": content } }] })
+            .to_string()
+    }
+
+    fn items() -> Vec<TranslationItem> {
+        vec![
+            TranslationItem {
+                id: 7,
+                text: "Hello there.".to_string(),
+            },
+            TranslationItem {
+                id: 8,
+                text: "Thanks for coming.".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn maps_translations_back_by_id() {
+        let body = completion(
+            "```json\n{\"segments\": [{\"id\": 8, \"text\": \"Gracias por venir.\"}, \
+             {\"id\": 7, \"text\": \" Hola. \"}]}\n```",
+        );
+
+        let translated = parse_translations(&body, &items()).expect("valid translation");
+        assert_eq!(translated, ["Hola.", "Gracias por venir."]);
+    }
+
+    #[test]
+    fn rejects_replies_with_missing_segments() {
+        let body = completion("{\"segments\": [{\"id\": 7, \"text\": \"Hola.\"}]}");
+
+        assert!(matches!(
+            parse_translations(&body, &items()),
+            Err(TranslationError::InvalidResponse(message)) if message.contains("segment 8")
+        ));
+    }
+
+    #[test]
+    fn batches_by_count_and_length() {
+        let segment = |text: &str| Segment {
+            start: 0.0,
+            end: 1.0,
+            text: text.to_string(),
+            words: Vec::new(),
+        };
+        let short = vec![segment("short"); 95];
+        assert_eq!(batch_ranges(&short), [0..40, 40..80, 80..95]);
+
+        let long = vec![segment(&"x".repeat(2_500)); 5];
+        assert_eq!(batch_ranges(&long), [0..2, 2..4, 4..5]);
+        assert!(batch_ranges(&[]).is_empty());
+    }
+}
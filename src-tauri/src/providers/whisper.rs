@@ -0,0 +1,584 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::models::transcript::{attach_words, Segment, Word};
+use crate::utils::retry::RetryableError;
+
+const TRANSCRIPTIONS_PATH: &str = "/v1/audio/transcriptions";
+const TRANSLATIONS_PATH: &str = "/v1/audio/translations";
+pub const WHISPER_MODEL: &str = "whisper-1";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+pub const MAX_UPLOAD_BYTES: u64 = 25 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseFormat {
+    Text,
+    VerboseJson,
+}
+
+impl ResponseFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::VerboseJson => "verbose_json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transcription {
+    pub text: String,
+    pub language: Option<String>,
+    pub duration_seconds: Option<f64>,
+    pub segments: Vec<Segment>,
+}
+
+#[derive(Debug)]
+pub enum WhisperError {
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    Network(reqwest::Error),
+    Api {
+        status: u16,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    InvalidResponse(String),
+    Local(String),
+}
+
+impl fmt::Display for WhisperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "Failed to read audio file {path}: {source}"),
+            Self::Network(source) => write!(f, "Transcription request failed: {source}"),
+            Self::Api {
+                status, message, ..
+            } => {
+                write!(f, "Transcription API returned status {status}: {message}")
+            }
+            Self::InvalidResponse(message) => {
+                write!(f, "Failed to parse transcription response: {message}")
+            }
+            Self::Local(message) => write!(f, "Local transcription failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for WhisperError {}
+
+impl RetryableError for WhisperError {
+    fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(source) => !source.is_builder(),
+            Self::Api { status, .. } => *status == 408 || *status == 429 || *status >= 500,
+            Self::Io { .. } | Self::InvalidResponse(_) | Self::Local(_) => false,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptionBody {
+    text: Option<String>,
+    language: Option<String>,
+    duration: Option<f64>,
+    segments: Option<Vec<SegmentBody>>,
+    words: Option<Vec<WordBody>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SegmentBody {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WordBody {
+    word: String,
+    start: f64,
+    end: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Authorization {
+    None,
+    Bearer(String),
+    ApiKeyHeader(String),
+}
+
+impl Authorization {
+    pub fn bearer(api_key: &str) -> Self {
+        match api_key.trim() {
+            "" => Self::None,
+            key => Self::Bearer(key.to_string()),
+        }
+    }
+}
+
+pub struct WhisperClient {
+    http: reqwest::Client,
+    transcriptions_url: String,
+    translations_url: String,
+    authorization: Authorization,
+    model: Option<String>,
+    word_timestamps: bool,
+}
+
+impl WhisperClient {
+    pub fn new(base_url: &str, api_key: &str) -> Result<Self, WhisperError> {
+        let base_url = base_url.trim().trim_end_matches('/');
+
+        Self::with_endpoints(
+            format!("{base_url}{TRANSCRIPTIONS_PATH}"),
+            format!("{base_url}{TRANSLATIONS_PATH}"),
+            Authorization::bearer(api_key),
+            Some(WHISPER_MODEL.to_string()),
+        )
+    }
+
+    pub fn with_endpoints(
+        transcriptions_url: String,
+        translations_url: String,
+        authorization: Authorization,
+        model: Option<String>,
+    ) -> Result<Self, WhisperError> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(WhisperError::Network)?;
+
+        Ok(Self {
+            http,
+            transcriptions_url,
+            translations_url,
+            authorization,
+            model,
+            word_timestamps: false,
+        })
+    }
+
+    /// Also asks for word-level timestamps on verbose JSON requests.
+    pub fn with_word_timestamps(mut self) -> Self {
+        self.word_timestamps = true;
+        self
+    }
+
+    pub async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        let form = self
+            .multipart_form(audio_path, format, self.word_timestamps)
+            .await?
+            .text("language", language.to_string());
+
+        self.send(&self.transcriptions_url, form, format).await
+    }
+
+    /// Translates the speech into English. Word timestamps are never requested here.
+    pub async fn translate(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        let form = self.multipart_form(audio_path, format, false).await?;
+        self.send(&self.translations_url, form, format).await
+    }
+
+    async fn multipart_form(
+        &self,
+        audio_path: &Path,
+        format: ResponseFormat,
+        word_timestamps: bool,
+    ) -> Result<Form, WhisperError> {
+        let mut form = build_form(audio_path, format).await?;
+        if word_timestamps && format == ResponseFormat::VerboseJson {
+            form = form
+                .text("timestamp_granularities[]", "word")
+                .text("timestamp_granularities[]", "segment");
+        }
+
+        Ok(match &self.model {
+            Some(model) => form.text("model", model.clone()),
+            None => form,
+        })
+    }
+
+    async fn send(
+        &self,
+        url: &str,
+        form: Form,
+        format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        eprintln!("[whisper] POST {url}");
+
+        let request = self.http.post(url).multipart(form);
+        let request = match &self.authorization {
+            Authorization::None => request,
+            Authorization::Bearer(key) => request.bearer_auth(key),
+            Authorization::ApiKeyHeader(key) => request.header("api-key", key),
+        };
+
+        let response = request.send().await.map_err(WhisperError::Network)?;
+        let status = response.status();
+        let retry_after = parse_retry_after(response.headers());
+        let body = response.text().await.map_err(WhisperError::Network)?;
+
+        if !status.is_success() {
+            return Err(WhisperError::Api {
+                status: status.as_u16(),
+                message: parse_error_message(&body),
+                retry_after,
+            });
+        }
+
+        parse_transcription(&body, format)
+    }
+}
+
+async fn build_form(audio_path: &Path, format: ResponseFormat) -> Result<Form, WhisperError> {
+    let bytes = fs::read(audio_path)
+        .await
+        .map_err(|source| WhisperError::Io {
+            path: audio_path.display().to_string(),
+            source,
+        })?;
+
+    let file_name = audio_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("audio.mp3")
+        .to_string();
+
+    let file_part = Part::bytes(bytes)
+        .file_name(file_name)
+        .mime_str(audio_mime_type(audio_path))
+        .map_err(WhisperError::Network)?;
+
+    Ok(Form::new()
+        .text("response_format", format.as_str())
+        .part("file", file_part))
+}
+
+fn audio_mime_type(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("wav") => "audio/wav",
+        Some("flac") => "audio/flac",
+        Some("ogg") => "audio/ogg",
+        Some("m4a") => "audio/mp4",
+        _ => "audio/mpeg",
+    }
+}
+
+pub(crate) fn parse_error_message(body: &str) -> String {
+    serde_json::from_str::<ErrorBody>(body)
+        .map(|parsed| parsed.error.message)
+        .unwrap_or_else(|_| body.trim().to_string())
+}
+
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    let seconds = value.parse::<f64>().ok()?;
+
+    (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+fn parse_transcription(body: &str, format: ResponseFormat) -> Result<Transcription, WhisperError> {
+    if format == ResponseFormat::Text {
+        return Ok(Transcription {
+            text: body.trim().to_string(),
+            language: None,
+            duration_seconds: None,
+            segments: Vec::new(),
+        });
+    }
+
+    let parsed: TranscriptionBody = serde_json::from_str(body)
+        .map_err(|error| WhisperError::InvalidResponse(error.to_string()))?;
+
+    let mut segments: Vec<Segment> = parsed
+        .segments
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|segment| segment.start.is_finite() && segment.end.is_finite())
+        .map(|segment| {
+            let start = segment.start.max(0.0);
+            Segment {
+                start,
+                end: segment.end.max(start),
+                text: segment.text.trim().to_string(),
+                words: Vec::new(),
+            }
+        })
+        .collect();
+
+    let words = parsed
+        .words
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|word| word.start.is_finite() && word.end.is_finite())
+        .map(|word| {
+            let start = word.start.max(0.0);
+            Word {
+                start,
+                end: word.end.max(start),
+                text: word.word.trim().to_string(),
+            }
+        })
+        .filter(|word| !word.text.is_empty())
+        .collect();
+    attach_words(&mut segments, words);
+
+    Ok(Transcription {
+        text: parsed.text.unwrap_or_default().trim().to_string(),
+        language: parsed.language,
+        duration_seconds: parsed.duration,
+        segments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+
+    use super::{
+        parse_error_message, parse_retry_after, parse_transcription, Authorization,
+        ResponseFormat, WhisperClient, WhisperError,
+    };
+    use crate::utils::retry::RetryableError;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const VERBOSE_BODY: &str = r#"{
+        "task": "transcribe",
+        "language": "english",
+        "duration": 12.5,
+        "text": "Hello there. General Kenobi.",
+        "segments": [
+            {"id": 0, "start": 0.0, "end": 4.2, "text": " Hello there."},
+            {"id": 1, "start": 4.2, "end": 12.5, "text": " General Kenobi."}
+        ]
+    }"#;
+
+    async fn serve_once(
+        status_line: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind stand-in server");
+        let address = listener.local_addr().expect("stand-in server address");
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.expect("accept connection");
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 8192];
+
+            loop {
+                let read = socket.read(&mut buffer).await.expect("read request");
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..header_end]
+                        .lines()
+                        .find_map(|line| {
+                            line.to_ascii_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|value| value.trim().parse::<usize>().unwrap_or(0))
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= header_end + 4 + content_length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "{status_line}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket
+                .write_all(response.as_bytes())
+                .await
+                .expect("write response");
+            String::from_utf8_lossy(&request).to_string()
+        });
+
+        (format!("http://{address}"), handle)
+    }
+
+    #[test]
+    fn parses_verbose_json_segments() {
+        let transcription =
+            parse_transcription(VERBOSE_BODY, ResponseFormat::VerboseJson).expect("valid response");
+        assert_eq!(transcription.segments.len(), 2);
+        assert_eq!(transcription.segments[0].text, "Hello there.");
+        assert!((transcription.segments[1].start - 4.2).abs() < 0.001);
+        assert_eq!(transcription.duration_seconds, Some(12.5));
+    }
+
+    #[test]
+    fn attaches_word_timestamps_to_segments() {
+        let body = r#"{
+            "text": "Hello there. General Kenobi.",
+            "segments": [
+                {"start": 0.0, "end": 4.2, "text": " Hello there."},
+                {"start": 4.2, "end": 12.5, "text": " General Kenobi."}
+            ],
+            "words": [
+                {"word": "Hello", "start": 0.3, "end": 0.9},
+                {"word": "there", "start": 1.0, "end": 1.6},
+                {"word": "General", "start": 5.0, "end": 5.8},
+                {"word": "Kenobi", "start": 6.0, "end": 7.1}
+            ]
+        }"#;
+        let transcription =
+            parse_transcription(body, ResponseFormat::VerboseJson).expect("valid response");
+        assert_eq!(transcription.segments[0].words.len(), 2);
+        assert_eq!(transcription.segments[1].words[0].text, "General");
+        assert!((transcription.segments[1].words[1].end - 7.1).abs() < 0.001);
+
+        let segments_only =
+            parse_transcription(VERBOSE_BODY, ResponseFormat::VerboseJson).expect("valid response");
+        assert!(segments_only.segments[0].words.is_empty());
+    }
+
+    #[test]
+    fn clamps_invalid_segment_timestamps() {
+        let body = r#"{"text": "x", "segments": [{"start": -1.0, "end": -2.0, "text": "x"}]}"#;
+        let transcription =
+            parse_transcription(body, ResponseFormat::VerboseJson).expect("valid response");
+        assert_eq!(transcription.segments[0].start, 0.0);
+        assert_eq!(transcription.segments[0].end, 0.0);
+    }
+
+    #[test]
+    fn extracts_api_error_message() {
+        let body = r#"{"error": {"message": "Incorrect API key provided", "type": "invalid_request_error"}}"#;
+        assert_eq!(parse_error_message(body), "Incorrect API key provided");
+        assert_eq!(parse_error_message("Bad gateway"), "Bad gateway");
+    }
+
+    #[test]
+    fn classifies_retryable_api_errors() {
+        let api_error = |status| WhisperError::Api {
+            status,
+            message: String::new(),
+            retry_after: None,
+        };
+
+        assert!(api_error(429).is_retryable());
+        assert!(api_error(500).is_retryable());
+        assert!(api_error(503).is_retryable());
+        assert!(!api_error(400).is_retryable());
+        assert!(!api_error(401).is_retryable());
+        assert!(!WhisperError::InvalidResponse("bad json".to_string()).is_retryable());
+    }
+
+    #[test]
+    fn reads_retry_after_seconds() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("12"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(12)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn uploads_to_configured_base_url() {
+        let (base_url, server) = serve_once("HTTP/1.1 200 OK", VERBOSE_BODY).await;
+        let audio = std::env::temp_dir().join("whisper-client-test.mp3");
+        tokio::fs::write(&audio, b"fake audio")
+            .await
+            .expect("write audio fixture");
+
+        let client = WhisperClient::new(&format!("{base_url}/"), "test-key")
+            .expect("client")
+            .with_word_timestamps();
+        let transcription = client
+            .transcribe(&audio, "en", ResponseFormat::VerboseJson)
+            .await
+            .expect("transcription should succeed");
+        let request = server.await.expect("server task");
+        let _ = tokio::fs::remove_file(&audio).await;
+
+        assert!(request.starts_with("POST /v1/audio/transcriptions "));
+        assert!(request.contains("Bearer test-key"));
+        assert!(request.contains("verbose_json"));
+        assert!(request.contains("timestamp_granularities[]"));
+        assert_eq!(transcription.segments.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sends_api_key_header_to_explicit_endpoint() {
+        let (base_url, server) = serve_once("HTTP/1.1 200 OK", VERBOSE_BODY).await;
+        let audio = std::env::temp_dir().join("whisper-client-azure-test.mp3");
+        tokio::fs::write(&audio, b"fake audio")
+            .await
+            .expect("write audio fixture");
+
+        let client = WhisperClient::with_endpoints(
+            format!("{base_url}/openai/deployments/whisper/audio/transcriptions?api-version=1"),
+            format!("{base_url}/openai/deployments/whisper/audio/translations?api-version=1"),
+            Authorization::ApiKeyHeader("azure-key".to_string()),
+            None,
+        )
+        .expect("client");
+        client
+            .translate(&audio, ResponseFormat::VerboseJson)
+            .await
+            .expect("translation should succeed");
+        let request = server.await.expect("server task");
+        let _ = tokio::fs::remove_file(&audio).await;
+
+        assert!(request
+            .starts_with("POST /openai/deployments/whisper/audio/translations?api-version=1 "));
+        assert!(request.to_ascii_lowercase().contains("api-key: azure-key"));
+        assert!(!request.contains("Bearer"));
+        assert!(!request.contains("name=\"model\""));
+        assert!(!request.contains("timestamp_granularities[]"));
+    }
+}
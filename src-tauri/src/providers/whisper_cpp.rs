@@ -0,0 +1,242 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::fs;
+
+use crate::models::settings::AppSettings;
+use crate::models::transcript::Segment;
+use crate::providers::whisper::{ResponseFormat, Transcription, WhisperError};
+use crate::providers::{ProviderCapabilities, TranscriptionProvider};
+use crate::utils::ffmpeg::{self, Sidecars};
+use crate::utils::{srt, temp};
+
+#[cfg(target_os = "windows")]
+const WHISPER_CPP_SIDECAR_NAME: &str = "whisper-cli-x86_64-pc-windows-msvc.exe";
+#[cfg(target_os = "linux")]
+const WHISPER_CPP_SIDECAR_NAME: &str = "whisper-cli-x86_64-unknown-linux-gnu";
+#[cfg(target_os = "macos")]
+const WHISPER_CPP_SIDECAR_NAME: &str = "whisper-cli-aarch64-apple-darwin";
+
+const WHISPER_CPP_FALLBACK_BINARY: &str = "whisper-cli";
+const TRANSCRIBE_TIMEOUT: Duration = Duration::from_secs(3 * 60 * 60);
+
+pub fn capabilities() -> ProviderCapabilities {
+    ProviderCapabilities {
+        max_upload_bytes: u64::MAX,
+        audio_format: "wav",
+        response_formats: vec![ResponseFormat::Text, ResponseFormat::VerboseJson],
+        word_timestamps: false,
+        translation: true,
+        requires_api_key: false,
+        price_per_minute_usd: 0.0,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OutputBody {
+    transcription: Vec<OutputSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OutputSegment {
+    offsets: OutputOffsets,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OutputOffsets {
+    from: i64,
+    to: i64,
+}
+
+fn parse_json_output(body: &str) -> Result<Vec<Segment>, WhisperError> {
+    let parsed: OutputBody = serde_json::from_str(body)
+        .map_err(|error| WhisperError::InvalidResponse(error.to_string()))?;
+
+    Ok(parsed
+        .transcription
+        .into_iter()
+        .map(|segment| {
+            let start = segment.offsets.from.max(0) as f64 / 1000.0;
+            Segment {
+                start,
+                end: (segment.offsets.to as f64 / 1000.0).max(start),
+                text: segment.text.trim().to_string(),
+                words: Vec::new(),
+            }
+        })
+        .filter(|segment| !segment.text.is_empty())
+        .collect())
+}
+
+fn output_file(base: &Path, extension: &str) -> PathBuf {
+    let mut path = OsString::from(base.as_os_str());
+    path.push(".");
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+pub struct WhisperCppProvider {
+    binary: PathBuf,
+    model_path: PathBuf,
+}
+
+impl WhisperCppProvider {
+    pub fn new(sidecars: &Sidecars, settings: &AppSettings) -> Result<Self, WhisperError> {
+        let model_path = settings
+            .whisper_cpp_model_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .ok_or_else(|| WhisperError::Local("No whisper.cpp model is selected.".to_string()))?;
+
+        if !model_path.is_file() {
+            return Err(WhisperError::Local(format!(
+                "Model file not found: {}",
+                model_path.display()
+            )));
+        }
+
+        let binary = match &sidecars.whisper_cli {
+            Some(path) => path.clone(),
+            None => sidecars
+                .find(WHISPER_CPP_SIDECAR_NAME)
+                .unwrap_or_else(|_| PathBuf::from(WHISPER_CPP_FALLBACK_BINARY)),
+        };
+
+        Ok(Self { binary, model_path })
+    }
+
+    async fn run(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        translate: bool,
+    ) -> Result<Transcription, WhisperError> {
+        let output_base = audio_path.with_extension("whisper");
+        let json_path = output_file(&output_base, "json");
+        let srt_path = output_file(&output_base, "srt");
+
+        let model = self.model_path.to_string_lossy();
+        let audio = audio_path.to_string_lossy();
+        let base = output_base.to_string_lossy();
+        let mut args = vec![
+            "-m", &model, "-f", &audio, "-l", language, "-of", &base, "-oj", "-osrt", "-np",
+        ];
+        if translate {
+            args.push("-tr");
+        }
+
+        let result = ffmpeg::execute_command(self.binary.clone(), &args, TRANSCRIBE_TIMEOUT, None)
+            .await
+            .map_err(|error| WhisperError::Local(error.to_string()));
+
+        let segments = match result {
+            Ok(_) => match fs::read_to_string(&json_path).await {
+                Ok(body) => parse_json_output(&body),
+                Err(_) => fs::read_to_string(&srt_path)
+                    .await
+                    .map_err(|error| {
+                        WhisperError::Local(format!("whisper.cpp produced no output: {error}"))
+                    })
+                    .and_then(|body| srt::parse_srt(&body).map_err(WhisperError::InvalidResponse)),
+            },
+            Err(error) => Err(error),
+        };
+
+        temp::remove_file(&json_path).await;
+        temp::remove_file(&srt_path).await;
+
+        let segments = segments?;
+        Ok(Transcription {
+            text: segments
+                .iter()
+                .map(|segment| segment.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+            language: Some(language.to_string()),
+            duration_seconds: None,
+            segments,
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for WhisperCppProvider {
+    fn name(&self) -> &'static str {
+        "whisper.cpp (offline)"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        capabilities()
+    }
+
+    async fn transcribe(
+        &self,
+        audio_path: &Path,
+        language: &str,
+        _format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.run(audio_path, language, false).await
+    }
+
+    async fn translate(
+        &self,
+        audio_path: &Path,
+        _format: ResponseFormat,
+    ) -> Result<Transcription, WhisperError> {
+        self.run(audio_path, "auto", true).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{output_file, parse_json_output};
+
+    #[test]
+    fn parses_whisper_cpp_json_offsets() {
+        let body = r#"{
+            "systeminfo": "AVX = 1",
+            "model": {"type": "base"},
+            "result": {"language": "en"},
+            "transcription": [
+                {
+                    "timestamps": {"from": "00:00:00,000", "to": "00:00:04,200"},
+                    "offsets": {"from": 0, "to": 4200},
+                    "text": " Hello there."
+                },
+                {
+                    "timestamps": {"from": "00:00:04,200", "to": "00:00:04,200"},
+                    "offsets": {"from": 4200, "to": 4200},
+                    "text": " "
+                },
+                {
+                    "timestamps": {"from": "00:00:04,200", "to": "00:00:12,500"},
+                    "offsets": {"from": 4200, "to": 12500},
+                    "text": " General Kenobi."
+                }
+            ]
+        }"#;
+
+        let segments = parse_json_output(body).expect("valid output");
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text, "Hello there.");
+        assert!((segments[1].start - 4.2).abs() < 0.0001);
+        assert!((segments[1].end - 12.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn appends_output_extensions_to_base_name() {
+        let base = Path::new("/tmp/job/chunk_001.whisper");
+        assert_eq!(
+            output_file(base, "json"),
+            Path::new("/tmp/job/chunk_001.whisper.json")
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::models::style::{ass_color, SpeakerStyle, StylePreset, SubtitleStyle};
+use crate::models::transcript::{Segment, Word};
+
+// Matches libass's default canvas for SRT input, so style sizes mean the same thing
+// whether they are burned in from an SRT or written into an ASS file.
+const PLAY_RES_X: u32 = 384;
+const PLAY_RES_Y: u32 = 288;
+const DEFAULT_STYLE_NAME: &str = "Default";
+const KARAOKE_SUFFIX: &str = " Karaoke";
+// Keeps speaker styles from colliding with `Default` and its karaoke variant.
+const SPEAKER_STYLE_PREFIX: &str = "Speaker ";
+
+/// `H:MM:SS.cc`, the centisecond clock ASS uses.
+pub fn format_timestamp(seconds: f64) -> String {
+    let total_centis = if seconds.is_finite() && seconds > 0.0 {
+        (seconds * 100.0).round() as u64
+    } else {
+        0
+    };
+
+    let hours = total_centis / 360_000;
+    let minutes = (total_centis % 360_000) / 6_000;
+    let secs = (total_centis % 6_000) / 100;
+    let centis = total_centis % 100;
+
+    format!("{hours}:{minutes:02}:{secs:02}.{centis:02}")
+}
+
+// ASS has no escape for override braces or backslashes, so swap them for look-alikes
+// that cannot start a tag.
+fn escape_text(text: &str) -> String {
+    text.replace('{', "(").replace('}', ")").replace('\\', "/")
+}
+
+// Style lines and the fields before `Text` are comma-separated, with no escape for commas.
+fn field(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(',', ";")
+}
+
+fn plain_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(escape_text)
+        .collect::<Vec<_>>()
+        .join("\\N")
+}
+
+fn centis_between(from: f64, to: f64) -> u64 {
+    ((to - from) * 100.0).round().max(0.0) as u64
+}
+
+/// One `{\kNN}` per word so the highlight follows the speech. The cue's own words are
+/// used when they line up one-to-one, since providers drop punctuation from word timings.
+fn karaoke_text(text: &str, start: f64, words: &[Word]) -> String {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let labels: Vec<&str> = if tokens.len() == words.len() {
+        tokens
+    } else {
+        words.iter().map(|word| word.text.as_str()).collect()
+    };
+
+    let mut output = String::new();
+    let mut elapsed = centis_between(start, words[0].start);
+    if elapsed > 0 {
+        output.push_str(&format!("{{\\k{elapsed}}}"));
+    }
+
+    for (index, (word, label)) in words.iter().zip(labels).enumerate() {
+        let until = match words.get(index + 1) {
+            Some(next) => centis_between(start, next.start),
+            None => centis_between(start, word.end),
+        }
+        .max(elapsed);
+        if index > 0 {
+            output.push(' ');
+        }
+        output.push_str(&format!("{{\\k{}}}{}", until - elapsed, escape_text(label)));
+        elapsed = until;
+    }
+
+    output
+}
+
+/// Splits a leading `<speaker>:` label off the cue when the speaker has a style.
+fn split_speaker<'a>(
+    text: &'a str,
+    speakers: &'a [SpeakerStyle],
+) -> Option<(&'a SpeakerStyle, &'a str)> {
+    let (label, rest) = text.split_once(':')?;
+    let label = label.trim();
+    let speaker = speakers
+        .iter()
+        .find(|speaker| speaker.speaker.trim().eq_ignore_ascii_case(label))?;
+
+    Some((speaker, rest.trim_start()))
+}
+
+fn style_line(name: &str, style: &SubtitleStyle, karaoke: bool) -> String {
+    let color = |hex: &str| ass_color(hex).unwrap_or_else(|| "&H00FFFFFF".to_string());
+    let flag = |enabled: bool| if enabled { -1 } else { 0 };
+    // Karaoke fills from SecondaryColour to PrimaryColour, so the highlight goes first.
+    let (primary, secondary) = if karaoke {
+        (&style.highlight_color, &style.primary_color)
+    } else {
+        (&style.primary_color, &style.primary_color)
+    };
+
+    format!(
+        "Style: {name},{},{},{},{},{},&H00000000,{},{},0,0,100,100,0,0,1,{},0,{},{},{},{},1\n",
+        field(&style.font_name),
+        style.font_size,
+        color(primary),
+        color(secondary),
+        color(&style.outline_color),
+        flag(style.bold),
+        flag(style.italic),
+        style.outline_width,
+        style.ass_alignment(),
+        style.margin_horizontal,
+        style.margin_horizontal,
+        style.margin_vertical
+    )
+}
+
+/// Renders an ASS script styled by `preset`, using its style for `language` when it has
+/// one. Cues labelled with a styled speaker get that speaker's style, and cues with word
+/// timings get karaoke tags under a `<style> Karaoke` variant.
+pub fn generate_ass(segments: &[Segment], preset: &StylePreset, language: Option<&str>) -> String {
+    let base = preset.style_for_language(language);
+    let mut styles: Vec<(String, &SubtitleStyle, bool)> =
+        vec![(DEFAULT_STYLE_NAME.to_string(), base, false)];
+    let mut events = String::new();
+
+    for segment in segments {
+        let (speaker, text) = match split_speaker(&segment.text, &preset.speaker_styles) {
+            Some((speaker, text)) => (Some(speaker), text),
+            None => (None, segment.text.as_str()),
+        };
+        let speaker_name = speaker
+            .map(|speaker| field(&speaker.speaker))
+            .unwrap_or_default();
+        let (style_name, style) = match speaker {
+            Some(speaker) => (
+                format!("{SPEAKER_STYLE_PREFIX}{speaker_name}"),
+                &speaker.style,
+            ),
+            None => (DEFAULT_STYLE_NAME.to_string(), base),
+        };
+        let karaoke = !segment.words.is_empty();
+        let style_name = if karaoke {
+            format!("{style_name}{KARAOKE_SUFFIX}")
+        } else {
+            style_name
+        };
+        if !styles.iter().any(|(name, _, _)| *name == style_name) {
+            styles.push((style_name.clone(), style, karaoke));
+        }
+
+        let text = if karaoke {
+            karaoke_text(text, segment.start, &segment.words)
+        } else {
+            plain_text(text)
+        };
+        events.push_str(&format!(
+            "Dialogue: 0,{},{},{style_name},{speaker_name},0,0,0,,{text}\n",
+            format_timestamp(segment.start),
+            format_timestamp(segment.end)
+        ));
+    }
+
+    let mut output = format!(
+        "[Script Info]\nScriptType: v4.00+\nPlayResX: {PLAY_RES_X}\nPlayResY: {PLAY_RES_Y}\nWrapStyle: 0\nScaledBorderAndShadow: yes\n\n"
+    );
+    output.push_str("[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n");
+    for (name, style, karaoke) in &styles {
+        output.push_str(&style_line(name, style, *karaoke));
+    }
+    output.push_str("\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n");
+    output.push_str(&events);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_timestamp, generate_ass};
+    use crate::models::style::{
+        HorizontalAlignment, LanguageStyle, SpeakerStyle, StylePreset, SubtitleStyle,
+    };
+    use crate::models::transcript::{Segment, Word};
+
+    fn word(start: f64, end: f64, text: &str) -> Word {
+        Word {
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    fn segment(start: f64, end: f64, text: &str) -> Segment {
+        Segment {
+            start,
+            end,
+            text: text.to_string(),
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn formats_centisecond_timestamps() {
+        assert_eq!(format_timestamp(0.0), "0:00:00.00");
+        assert_eq!(format_timestamp(3723.456), "1:02:03.46");
+        assert_eq!(format_timestamp(-1.0), "0:00:00.00");
+    }
+
+    #[test]
+    fn writes_karaoke_tags_from_word_timings() {
+        let segments = vec![
+            Segment {
+                words: vec![word(1.2, 1.7, "Hello"), word(1.9, 2.6, "there")],
+                ..segment(1.0, 3.0, "Hello, there!")
+            },
+            segment(3.0, 4.0, "No {words}\nhere"),
+        ];
+
+        let script = generate_ass(&segments, &StylePreset::default(), None);
+        assert!(script.contains("Style: Default,Arial,22,&H00FFFFFF,&H00FFFFFF,&H00000000,"));
+        assert!(
+            script.contains("Style: Default Karaoke,Arial,22,&H0000FFFF,&H00FFFFFF,&H00000000,")
+        );
+        assert!(script.contains(
+            "Dialogue: 0,0:00:01.00,0:00:03.00,Default Karaoke,,0,0,0,,{\\k20}{\\k70}Hello, {\\k70}there!\n"
+        ));
+        assert!(script
+            .contains("Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,No (words)\\Nhere\n"));
+    }
+
+    #[test]
+    fn applies_language_and_speaker_styles() {
+        let preset = StylePreset {
+            language_styles: vec![LanguageStyle {
+                language: "de".to_string(),
+                style: SubtitleStyle {
+                    font_name: "Tiresias".to_string(),
+                    ..SubtitleStyle::default()
+                },
+            }],
+            speaker_styles: vec![SpeakerStyle {
+                speaker: "Anna".to_string(),
+                style: SubtitleStyle {
+                    italic: true,
+                    alignment: HorizontalAlignment::Left,
+                    ..SubtitleStyle::default()
+                },
+            }],
+            ..StylePreset::default()
+        };
+        let segments = vec![
+            segment(0.0, 1.0, "ANNA: Guten Tag."),
+            segment(1.0, 2.0, "Note: keine Sprecherin."),
+        ];
+
+        let script = generate_ass(&segments, &preset, Some("de"));
+        assert!(script.contains("Style: Default,Tiresias,22,"));
+        assert!(script.contains("Style: Speaker Anna,Arial,22,&H00FFFFFF,&H00FFFFFF,&H00000000,&H00000000,0,-1,0,0,100,100,0,0,1,2,0,1,10,10,16,1\n"));
+        assert!(script.contains(",Speaker Anna,Anna,0,0,0,,Guten Tag.\n"));
+        assert!(script.contains(",Default,,0,0,0,,Note: keine Sprecherin.\n"));
+
+        let english = generate_ass(&segments, &preset, Some("en"));
+        assert!(english.contains("Style: Default,Arial,22,"));
+    }
+
+    #[test]
+    fn keeps_speaker_styles_apart_from_default_and_commas_out_of_fields() {
+        let speaker = |name: &str, font_size| SpeakerStyle {
+            speaker: name.to_string(),
+            style: SubtitleStyle {
+                font_size,
+                ..SubtitleStyle::default()
+            },
+        };
+        let preset = StylePreset {
+            speaker_styles: vec![speaker("Default", 30), speaker("Smith, Jr.", 40)],
+            ..StylePreset::default()
+        };
+        let segments = vec![
+            segment(0.0, 1.0, "Plain line."),
+            segment(1.0, 2.0, "default: Styled line."),
+            segment(2.0, 3.0, "Smith, Jr.: Hello, all."),
+        ];
+
+        let script = generate_ass(&segments, &preset, None);
+        assert!(script.contains("Style: Default,Arial,22,"));
+        assert!(script.contains("Style: Speaker Default,Arial,30,"));
+        assert!(script.contains("Style: Speaker Smith; Jr.,Arial,40,"));
+        assert!(script.contains(",Default,,0,0,0,,Plain line.\n"));
+        assert!(script.contains(",Speaker Default,Default,0,0,0,,Styled line.\n"));
+        assert!(script.contains(",Speaker Smith; Jr.,Smith; Jr.,0,0,0,,Hello, all.\n"));
+    }
+}
@@ -1,11 +1,16 @@
 #![allow(dead_code)]
 
 use std::fmt;
-use std::path::PathBuf;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::time::Duration;
 
+#[cfg(feature = "gui")]
 use tauri::{AppHandle, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
 use tokio::time;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug, Clone)]
 pub struct FFmpegCommandOutput {
@@ -15,6 +20,76 @@ pub struct FFmpegCommandOutput {
     pub stderr: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FFmpegProgress {
+    pub out_time_seconds: f64,
+    pub speed: Option<f64>,
+    pub fraction: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SilenceInterval {
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+}
+
+#[derive(Debug)]
+struct ProgressParser {
+    total_duration_seconds: f64,
+    out_time_seconds: f64,
+    speed: Option<f64>,
+}
+
+impl ProgressParser {
+    fn new(total_duration_seconds: f64) -> Self {
+        Self {
+            total_duration_seconds,
+            out_time_seconds: 0.0,
+            speed: None,
+        }
+    }
+
+    // ffmpeg writes `key=value` lines and closes each block with a `progress=` line.
+    // Note that `out_time_ms` is reported in microseconds, just like `out_time_us`.
+    fn feed(&mut self, line: &str) -> Option<FFmpegProgress> {
+        let (key, value) = line.trim().split_once('=')?;
+        let value = value.trim();
+
+        match key {
+            "out_time_us" | "out_time_ms" => {
+                if let Ok(micros) = value.parse::<i64>() {
+                    self.out_time_seconds = micros.max(0) as f64 / 1_000_000.0;
+                }
+                None
+            }
+            "speed" => {
+                self.speed = value
+                    .trim_end_matches('x')
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|speed| speed.is_finite());
+                None
+            }
+            "progress" => {
+                let fraction = if value == "end" {
+                    1.0
+                } else if self.total_duration_seconds > 0.0 {
+                    (self.out_time_seconds / self.total_duration_seconds).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                Some(FFmpegProgress {
+                    out_time_seconds: self.out_time_seconds,
+                    speed: self.speed,
+                    fraction,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum FFmpegError {
     BinaryNotFound {
@@ -35,6 +110,9 @@ pub enum FFmpegError {
         command: String,
         timeout: Duration,
     },
+    Cancelled {
+        command: String,
+    },
 }
 
 impl fmt::Display for FFmpegError {
@@ -71,12 +149,26 @@ impl fmt::Display for FFmpegError {
                 "Command `{command}` exceeded timeout of {}s",
                 timeout.as_secs()
             ),
+            Self::Cancelled { command } => write!(f, "Command `{command}` was cancelled"),
         }
     }
 }
 
 impl std::error::Error for FFmpegError {}
 
+const CHUNK_COMMAND_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+const SILENCE_COMMAND_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+const SUBTITLE_COMMAND_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+const MUX_COMMAND_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+const BURN_IN_COMMAND_TIMEOUT: Duration = Duration::from_secs(6 * 60 * 60);
+const PREVIEW_COMMAND_TIMEOUT: Duration = Duration::from_secs(60);
+const PREVIEW_MAX_WIDTH: u32 = 1280;
+const AUDIO_BITRATE: &str = "64k";
+const AUDIO_BITS_PER_SECOND: f64 = 64_000.0;
+const AUDIO_CONTAINER_OVERHEAD: f64 = 1.02;
+const SILENCE_NOISE_THRESHOLD: &str = "-35dB";
+const SILENCE_MIN_DURATION_SECONDS: f64 = 0.5;
+
 #[cfg(target_os = "windows")]
 const FFMPEG_SIDECAR_NAME: &str = "ffmpeg-x86_64-pc-windows-msvc.exe";
 #[cfg(target_os = "windows")]
@@ -92,7 +184,50 @@ const FFMPEG_SIDECAR_NAME: &str = "ffmpeg-aarch64-apple-darwin";
 #[cfg(target_os = "macos")]
 const FFPROBE_SIDECAR_NAME: &str = "ffprobe-aarch64-apple-darwin";
 
-fn find_sidecar(app: &AppHandle, binary_name: &str) -> Result<PathBuf, FFmpegError> {
+/// Says where the external binaries live. The app searches next to the executable and in its
+/// resource directory; the CLI can also point at explicit binaries.
+#[derive(Debug, Clone, Default)]
+pub struct Sidecars {
+    pub resource_dir: Option<PathBuf>,
+    pub ffmpeg: Option<PathBuf>,
+    pub ffprobe: Option<PathBuf>,
+    pub whisper_cli: Option<PathBuf>,
+}
+
+impl Sidecars {
+    #[cfg(feature = "gui")]
+    pub fn from_app(app: &AppHandle) -> Self {
+        Self {
+            resource_dir: app.path().resource_dir().ok(),
+            ..Self::default()
+        }
+    }
+
+    pub fn find(&self, binary_name: &str) -> Result<PathBuf, FFmpegError> {
+        find_sidecar(self.resource_dir.as_deref(), binary_name)
+    }
+
+    pub fn ffmpeg(&self) -> Result<PathBuf, FFmpegError> {
+        match &self.ffmpeg {
+            Some(path) => Ok(path.clone()),
+            None => self.find(FFMPEG_SIDECAR_NAME),
+        }
+    }
+
+    pub fn ffprobe(&self) -> PathBuf {
+        match &self.ffprobe {
+            Some(path) => path.clone(),
+            None => self
+                .find(FFPROBE_SIDECAR_NAME)
+                .unwrap_or_else(|_| PathBuf::from("ffprobe")),
+        }
+    }
+}
+
+pub fn find_sidecar(
+    resource_dir: Option<&Path>,
+    binary_name: &str,
+) -> Result<PathBuf, FFmpegError> {
     let mut candidates: Vec<PathBuf> = Vec::new();
 
     if let Ok(exe_path) = std::env::current_exe() {
@@ -106,7 +241,7 @@ fn find_sidecar(app: &AppHandle, binary_name: &str) -> Result<PathBuf, FFmpegErr
         }
     }
 
-    if let Ok(resource_dir) = app.path().resource_dir() {
+    if let Some(resource_dir) = resource_dir {
         candidates.push(resource_dir.join(binary_name));
     }
 
@@ -130,61 +265,519 @@ fn find_sidecar(app: &AppHandle, binary_name: &str) -> Result<PathBuf, FFmpegErr
     })
 }
 
-pub fn get_ffmpeg_binary_path(app: &AppHandle) -> Result<PathBuf, FFmpegError> {
-    find_sidecar(app, FFMPEG_SIDECAR_NAME)
+pub async fn execute_ffmpeg_command(
+    sidecars: &Sidecars,
+    args: &[&str],
+    timeout: Duration,
+) -> Result<FFmpegCommandOutput, FFmpegError> {
+    let binary = sidecars.ffmpeg()?;
+    execute_command(binary, args, timeout, None).await
 }
 
-pub fn get_ffprobe_binary_path(app: &AppHandle) -> Result<PathBuf, FFmpegError> {
-    match find_sidecar(app, FFPROBE_SIDECAR_NAME) {
-        Ok(path) => Ok(path),
-        Err(_) => Ok(PathBuf::from("ffprobe")),
-    }
+pub async fn execute_ffmpeg_command_with_cancel(
+    sidecars: &Sidecars,
+    args: &[&str],
+    timeout: Duration,
+    cancel: &CancellationToken,
+) -> Result<FFmpegCommandOutput, FFmpegError> {
+    let binary = sidecars.ffmpeg()?;
+    execute_command(binary, args, timeout, Some(cancel)).await
 }
 
-pub async fn execute_ffmpeg_command(
-    app: &AppHandle,
+pub async fn execute_ffprobe_command(
+    sidecars: &Sidecars,
     args: &[&str],
     timeout: Duration,
 ) -> Result<FFmpegCommandOutput, FFmpegError> {
-    let binary = get_ffmpeg_binary_path(app)?;
-    execute_command(binary, args, timeout).await
+    let binary = sidecars.ffprobe();
+    execute_command(binary, args, timeout, None).await
 }
 
-pub async fn execute_ffprobe_command(
-    app: &AppHandle,
+pub async fn execute_ffprobe_command_with_cancel(
+    sidecars: &Sidecars,
     args: &[&str],
     timeout: Duration,
+    cancel: &CancellationToken,
 ) -> Result<FFmpegCommandOutput, FFmpegError> {
-    let binary = get_ffprobe_binary_path(app)?;
-    execute_command(binary, args, timeout).await
+    let binary = sidecars.ffprobe();
+    execute_command(binary, args, timeout, Some(cancel)).await
 }
 
-async fn execute_command(
-    binary: PathBuf,
+pub async fn execute_ffmpeg_command_with_progress<F>(
+    sidecars: &Sidecars,
     args: &[&str],
     timeout: Duration,
+    cancel: &CancellationToken,
+    total_duration_seconds: f64,
+    on_progress: F,
+) -> Result<FFmpegCommandOutput, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let binary = sidecars.ffmpeg()?;
+    execute_command_with_progress(
+        binary,
+        args,
+        timeout,
+        Some(cancel),
+        total_duration_seconds,
+        on_progress,
+    )
+    .await
+}
+
+pub fn estimate_upload_bytes(duration_seconds: f64) -> u64 {
+    let bytes = duration_seconds.max(0.0) * AUDIO_BITS_PER_SECOND / 8.0 * AUDIO_CONTAINER_OVERHEAD;
+    bytes.ceil() as u64
+}
+
+pub fn max_upload_seconds(limit_bytes: u64) -> f64 {
+    limit_bytes as f64 * 8.0 / AUDIO_BITS_PER_SECOND / AUDIO_CONTAINER_OVERHEAD
+}
+
+fn audio_codec_args(output: &Path) -> [&'static str; 4] {
+    match output
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("wav") => ["-c:a", "pcm_s16le", "-f", "wav"],
+        Some("flac") => ["-c:a", "flac", "-f", "flac"],
+        _ => ["-c:a", "libmp3lame", "-b:a", AUDIO_BITRATE],
+    }
+}
+
+/// A media file and the audio stream to read from it. `stream` is the absolute stream
+/// index reported by ffprobe; `None` reads the first audio stream.
+#[derive(Debug, Clone, Copy)]
+pub struct AudioInput<'a> {
+    pub path: &'a str,
+    pub stream: Option<u32>,
+}
+
+impl AudioInput<'_> {
+    fn map(&self) -> String {
+        match self.stream {
+            Some(index) => format!("0:{index}"),
+            None => "0:a:0".to_string(),
+        }
+    }
+}
+
+// `-ss` before `-i` seeks the demuxer to the nearest keyframe and then decodes up to
+// the exact start, so the audio begins on the requested sample rather than on a keyframe.
+fn chunk_audio_args(
+    input: AudioInput<'_>,
+    start_seconds: f64,
+    duration_seconds: f64,
+    output: &Path,
+) -> Vec<String> {
+    let mut args = vec![
+        "-y".to_string(),
+        "-ss".to_string(),
+        format!("{start_seconds:.6}"),
+        "-i".to_string(),
+        input.path.to_string(),
+        "-t".to_string(),
+        format!("{duration_seconds:.6}"),
+        "-map".to_string(),
+        input.map(),
+        "-vn".to_string(),
+        "-sn".to_string(),
+        "-dn".to_string(),
+        "-ac".to_string(),
+        "1".to_string(),
+        "-ar".to_string(),
+        "16000".to_string(),
+    ];
+    args.extend(audio_codec_args(output).iter().map(|arg| arg.to_string()));
+    args.push(output.to_string_lossy().to_string());
+    args
+}
+
+pub async fn extract_chunk_audio<F>(
+    sidecars: &Sidecars,
+    input: AudioInput<'_>,
+    start_seconds: f64,
+    duration_seconds: f64,
+    output: &Path,
+    cancel: &CancellationToken,
+    on_progress: F,
+) -> Result<FFmpegCommandOutput, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let args = chunk_audio_args(input, start_seconds, duration_seconds, output);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    execute_ffmpeg_command_with_progress(
+        sidecars,
+        &args,
+        CHUNK_COMMAND_TIMEOUT,
+        cancel,
+        duration_seconds,
+        on_progress,
+    )
+    .await
+}
+
+fn subtitle_stream_args(input: &str, stream_index: u32, codec: &str, output: &Path) -> Vec<String> {
+    vec![
+        "-y".to_string(),
+        "-i".to_string(),
+        input.to_string(),
+        "-map".to_string(),
+        format!("0:{stream_index}"),
+        "-c:s".to_string(),
+        codec.to_string(),
+        output.to_string_lossy().to_string(),
+    ]
+}
+
+/// Converts one text subtitle stream of `input` with the given subtitle encoder
+/// (`srt`, `webvtt`, ...). The whole container has to be read, so this takes as long as
+/// demuxing the file.
+pub async fn extract_subtitle_stream(
+    sidecars: &Sidecars,
+    input: &str,
+    stream_index: u32,
+    codec: &str,
+    output: &Path,
+    cancel: &CancellationToken,
 ) -> Result<FFmpegCommandOutput, FFmpegError> {
-    let command_display = format!("{} {}", binary.display(), args.join(" "));
-    println!("[ffmpeg] executing: {command_display}");
+    let args = subtitle_stream_args(input, stream_index, codec, output);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    execute_ffmpeg_command_with_cancel(sidecars, &args, SUBTITLE_COMMAND_TIMEOUT, cancel).await
+}
+
+/// A subtitle file added as a new stream when muxing.
+#[derive(Debug, Clone, Copy)]
+pub struct SubtitleTrackInput<'a> {
+    pub path: &'a str,
+    pub codec: &'a str,
+    pub language: Option<&'a str>,
+    pub title: Option<&'a str>,
+}
+
+/// Copies the streams of `input` selected by `keep_streams` into `output` and appends each
+/// of `tracks` as a subtitle stream. `existing_subtitle_codecs` has one entry per subtitle
+/// stream `keep_streams` selects, so the new ones can be addressed by output index.
+#[derive(Debug, Clone, Copy)]
+pub struct SubtitleMux<'a> {
+    pub input: &'a str,
+    pub keep_streams: &'a [String],
+    /// Encoder for each kept subtitle stream; `None` copies it.
+    pub existing_subtitle_codecs: &'a [Option<&'a str>],
+    pub tracks: &'a [SubtitleTrackInput<'a>],
+    pub output: &'a Path,
+}
+
+pub(crate) fn mux_subtitles_args(mux: &SubtitleMux<'_>) -> Vec<String> {
+    let mut args = vec!["-y".to_string(), "-i".to_string(), mux.input.to_string()];
+    for track in mux.tracks {
+        args.extend(["-i".to_string(), track.path.to_string()]);
+    }
+    for selector in mux.keep_streams {
+        args.extend(["-map".to_string(), selector.clone()]);
+    }
+    for input_index in 1..=mux.tracks.len() {
+        args.extend(["-map".to_string(), format!("{input_index}:0")]);
+    }
+
+    args.extend(["-c".to_string(), "copy".to_string()]);
+    for (stream, codec) in mux.existing_subtitle_codecs.iter().enumerate() {
+        if let Some(codec) = codec {
+            args.extend([format!("-c:s:{stream}"), codec.to_string()]);
+        }
+    }
+    for (offset, track) in mux.tracks.iter().enumerate() {
+        let stream = mux.existing_subtitle_codecs.len() + offset;
+        args.extend([format!("-c:s:{stream}"), track.codec.to_string()]);
+        if let Some(language) = track.language {
+            args.extend([
+                format!("-metadata:s:s:{stream}"),
+                format!("language={language}"),
+            ]);
+        }
+        if let Some(title) = track.title {
+            args.extend([format!("-metadata:s:s:{stream}"), format!("title={title}")]);
+        }
+    }
+
+    args.push(mux.output.to_string_lossy().to_string());
+    args
+}
+
+pub async fn mux_subtitles<F>(
+    sidecars: &Sidecars,
+    mux: &SubtitleMux<'_>,
+    duration_seconds: f64,
+    cancel: &CancellationToken,
+    on_progress: F,
+) -> Result<FFmpegCommandOutput, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let args = mux_subtitles_args(mux);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    execute_ffmpeg_command_with_progress(
+        sidecars,
+        &args,
+        MUX_COMMAND_TIMEOUT,
+        cancel,
+        duration_seconds,
+        on_progress,
+    )
+    .await
+}
+
+// Filter arguments are parsed twice: once as an option value, where `\`, `'` and `:` are
+// special, then as part of the filtergraph, where `\`, `'`, `[`, `]`, `,` and `;` are.
+fn escape_filter_option(value: &str) -> String {
+    escape_chars(value, &['\\', '\'', ':'])
+}
+
+fn escape_filtergraph(value: &str) -> String {
+    escape_chars(value, &['\\', '\'', '[', ']', ',', ';'])
+}
+
+fn escape_chars(value: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if special.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// libass `subtitles` filter drawing `subtitle_path` with `force_style` overrides. Windows
+/// separators are turned into `/`, which ffmpeg accepts everywhere.
+fn subtitles_filter(subtitle_path: &str, force_style: &str) -> String {
+    let subtitle_path = subtitle_path.replace('\\', "/");
+    escape_filtergraph(&format!(
+        "subtitles=filename={}:force_style={}",
+        escape_filter_option(&subtitle_path),
+        escape_filter_option(force_style)
+    ))
+}
+
+/// Subtitles drawn onto the video with libass.
+#[derive(Debug, Clone, Copy)]
+pub struct BurnIn<'a> {
+    pub input: &'a str,
+    pub subtitle_path: &'a str,
+    pub force_style: &'a str,
+}
+
+fn burn_in_args(burn_in: &BurnIn<'_>, output: &Path) -> Vec<String> {
+    vec![
+        "-y".to_string(),
+        "-i".to_string(),
+        burn_in.input.to_string(),
+        "-map".to_string(),
+        "0:v:0".to_string(),
+        "-map".to_string(),
+        "0:a?".to_string(),
+        "-vf".to_string(),
+        subtitles_filter(burn_in.subtitle_path, burn_in.force_style),
+        "-c:v".to_string(),
+        "libx264".to_string(),
+        "-preset".to_string(),
+        "medium".to_string(),
+        "-crf".to_string(),
+        "20".to_string(),
+        "-pix_fmt".to_string(),
+        "yuv420p".to_string(),
+        "-c:a".to_string(),
+        "aac".to_string(),
+        "-b:a".to_string(),
+        "192k".to_string(),
+        "-movflags".to_string(),
+        "+faststart".to_string(),
+        output.to_string_lossy().to_string(),
+    ]
+}
+
+// `-copyts` keeps the original timestamps after the input seek, so the subtitles filter
+// draws the cue that is on screen at `timestamp_seconds` rather than the first one.
+fn preview_frame_args(burn_in: &BurnIn<'_>, timestamp_seconds: f64, output: &Path) -> Vec<String> {
+    let filter = format!(
+        "{},scale='min({PREVIEW_MAX_WIDTH},iw)':-2",
+        subtitles_filter(burn_in.subtitle_path, burn_in.force_style)
+    );
+
+    vec![
+        "-y".to_string(),
+        "-ss".to_string(),
+        format!("{timestamp_seconds:.3}"),
+        "-copyts".to_string(),
+        "-i".to_string(),
+        burn_in.input.to_string(),
+        "-map".to_string(),
+        "0:v:0".to_string(),
+        "-vf".to_string(),
+        filter,
+        "-frames:v".to_string(),
+        "1".to_string(),
+        "-q:v".to_string(),
+        "3".to_string(),
+        output.to_string_lossy().to_string(),
+    ]
+}
+
+pub async fn burn_in_subtitles<F>(
+    sidecars: &Sidecars,
+    burn_in: &BurnIn<'_>,
+    output: &Path,
+    duration_seconds: f64,
+    cancel: &CancellationToken,
+    on_progress: F,
+) -> Result<FFmpegCommandOutput, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let args = burn_in_args(burn_in, output);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
 
-    let result = time::timeout(timeout, tokio::process::Command::new(&binary).args(args).output()).await;
+    execute_ffmpeg_command_with_progress(
+        sidecars,
+        &args,
+        BURN_IN_COMMAND_TIMEOUT,
+        cancel,
+        duration_seconds,
+        on_progress,
+    )
+    .await
+}
+
+/// Renders the frame at `timestamp_seconds` with the subtitles drawn on it as a JPEG no
+/// wider than 1280 pixels.
+pub async fn render_preview_frame(
+    sidecars: &Sidecars,
+    burn_in: &BurnIn<'_>,
+    timestamp_seconds: f64,
+    output: &Path,
+) -> Result<FFmpegCommandOutput, FFmpegError> {
+    let args = preview_frame_args(burn_in, timestamp_seconds, output);
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    execute_ffmpeg_command(sidecars, &args, PREVIEW_COMMAND_TIMEOUT).await
+}
+
+pub async fn detect_silences<F>(
+    sidecars: &Sidecars,
+    input: AudioInput<'_>,
+    duration_seconds: f64,
+    cancel: &CancellationToken,
+    on_progress: F,
+) -> Result<Vec<SilenceInterval>, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let filter = format!(
+        "silencedetect=noise={SILENCE_NOISE_THRESHOLD}:d={SILENCE_MIN_DURATION_SECONDS}"
+    );
+    let map = input.map();
+
+    let output = execute_ffmpeg_command_with_progress(
+        sidecars,
+        &[
+            "-i", input.path, "-map", &map, "-vn", "-sn", "-dn", "-af", &filter, "-f", "null", "-",
+        ],
+        SILENCE_COMMAND_TIMEOUT,
+        cancel,
+        duration_seconds,
+        on_progress,
+    )
+    .await?;
+
+    Ok(parse_silences(&output.stderr, duration_seconds))
+}
+
+fn parse_silences(stderr: &str, duration_seconds: f64) -> Vec<SilenceInterval> {
+    let mut silences = Vec::new();
+    let mut open_start: Option<f64> = None;
+
+    for line in stderr.lines() {
+        if let Some(start) = read_silence_value(line, "silence_start:") {
+            open_start = Some(start.max(0.0));
+        } else if let Some(end) = read_silence_value(line, "silence_end:") {
+            if let Some(start) = open_start.take() {
+                silences.push(SilenceInterval {
+                    start_seconds: start,
+                    end_seconds: end.max(start),
+                });
+            }
+        }
+    }
 
-    let output = match result {
+    if let Some(start) = open_start {
+        if duration_seconds > start {
+            silences.push(SilenceInterval {
+                start_seconds: start,
+                end_seconds: duration_seconds,
+            });
+        }
+    }
+
+    silences
+}
+
+fn read_silence_value(line: &str, key: &str) -> Option<f64> {
+    let (_, rest) = line.split_once(key)?;
+    rest.split_whitespace()
+        .next()?
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+async fn run_with_limits<T>(
+    command_display: &str,
+    timeout: Duration,
+    cancel: Option<&CancellationToken>,
+    run: impl Future<Output = std::io::Result<T>>,
+) -> Result<T, FFmpegError> {
+    let run = time::timeout(timeout, run);
+
+    let result = match cancel {
+        Some(token) => tokio::select! {
+            result = run => result,
+            _ = token.cancelled() => {
+                return Err(FFmpegError::Cancelled {
+                    command: command_display.to_string(),
+                })
+            }
+        },
+        None => run.await,
+    };
+
+    match result {
         Ok(wait_result) => wait_result.map_err(|source| FFmpegError::SpawnFailed {
-            command: command_display.clone(),
+            command: command_display.to_string(),
             source,
-        })?,
-        Err(_) => return Err(FFmpegError::TimedOut {
-            command: command_display,
+        }),
+        Err(_) => Err(FFmpegError::TimedOut {
+            command: command_display.to_string(),
             timeout,
         }),
-    };
+    }
+}
 
-    let status_code = output.status.code().unwrap_or(-1);
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+fn finish_command(
+    command_display: String,
+    status: std::process::ExitStatus,
+    stdout: String,
+    stderr: String,
+) -> Result<FFmpegCommandOutput, FFmpegError> {
+    let status_code = status.code().unwrap_or(-1);
 
-    if !output.status.success() {
+    if !status.success() {
         return Err(FFmpegError::Failed {
             command: command_display,
             status_code,
@@ -200,3 +793,426 @@ async fn execute_command(
         stderr,
     })
 }
+
+pub(crate) async fn execute_command(
+    binary: PathBuf,
+    args: &[&str],
+    timeout: Duration,
+    cancel: Option<&CancellationToken>,
+) -> Result<FFmpegCommandOutput, FFmpegError> {
+    let command_display = format!("{} {}", binary.display(), args.join(" "));
+    eprintln!("[ffmpeg] executing: {command_display}");
+
+    let mut command = tokio::process::Command::new(&binary);
+    command.args(args).kill_on_drop(true);
+    let output = run_with_limits(&command_display, timeout, cancel, command.output()).await?;
+
+    finish_command(
+        command_display,
+        output.status,
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    )
+}
+
+async fn execute_command_with_progress<F>(
+    binary: PathBuf,
+    args: &[&str],
+    timeout: Duration,
+    cancel: Option<&CancellationToken>,
+    total_duration_seconds: f64,
+    mut on_progress: F,
+) -> Result<FFmpegCommandOutput, FFmpegError>
+where
+    F: FnMut(FFmpegProgress),
+{
+    let mut full_args = vec!["-progress", "pipe:1", "-nostats"];
+    full_args.extend_from_slice(args);
+    let command_display = format!("{} {}", binary.display(), full_args.join(" "));
+    eprintln!("[ffmpeg] executing: {command_display}");
+
+    let mut child = tokio::process::Command::new(&binary)
+        .args(&full_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|source| FFmpegError::SpawnFailed {
+            command: command_display.clone(),
+            source,
+        })?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let stderr_task = tokio::spawn(async move {
+        let mut text = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut text).await;
+        }
+        text
+    });
+
+    let run = async {
+        let mut parser = ProgressParser::new(total_duration_seconds);
+        let mut stdout_text = String::new();
+
+        if let Some(stdout) = stdout {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Some(line) = lines.next_line().await? {
+                if let Some(progress) = parser.feed(&line) {
+                    on_progress(progress);
+                }
+                stdout_text.push_str(&line);
+                stdout_text.push('\n');
+            }
+        }
+
+        let status = child.wait().await?;
+        Ok((status, stdout_text))
+    };
+
+    let result = run_with_limits(&command_display, timeout, cancel, run).await;
+    let stderr = match result {
+        Ok(_) => stderr_task.await.unwrap_or_default(),
+        Err(_) => {
+            stderr_task.abort();
+            String::new()
+        }
+    };
+    let (status, stdout) = result?;
+
+    finish_command(command_display, status, stdout, stderr)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+
+    use tokio_util::sync::CancellationToken;
+
+    use super::{
+        burn_in_args, chunk_audio_args, execute_command, execute_command_with_progress,
+        mux_subtitles_args, parse_silences, preview_frame_args, subtitle_stream_args,
+        subtitles_filter, AudioInput, BurnIn, FFmpegError, FFmpegProgress, ProgressParser,
+        SilenceInterval, SubtitleMux, SubtitleTrackInput,
+    };
+
+    #[tokio::test]
+    async fn cancelling_stops_running_command() {
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            canceller.cancel();
+        });
+
+        let started = Instant::now();
+        let result = execute_command(
+            PathBuf::from("sleep"),
+            &["5"],
+            Duration::from_secs(30),
+            Some(&token),
+        )
+        .await;
+
+        assert!(matches!(result, Err(FFmpegError::Cancelled { .. })));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn timing_out_reports_timeout() {
+        let result = execute_command(
+            PathBuf::from("sleep"),
+            &["5"],
+            Duration::from_millis(100),
+            None,
+        )
+        .await;
+
+        assert!(matches!(result, Err(FFmpegError::TimedOut { .. })));
+    }
+
+    #[test]
+    fn parses_progress_blocks() {
+        let mut parser = ProgressParser::new(10.0);
+        let block = [
+            "frame=0",
+            "out_time_us=2500000",
+            "out_time_ms=2500000",
+            "out_time=00:00:02.500000",
+            "speed=12.4x",
+        ];
+        for line in block {
+            assert_eq!(parser.feed(line), None);
+        }
+
+        let progress = parser.feed("progress=continue").expect("block complete");
+        assert!((progress.out_time_seconds - 2.5).abs() < 0.0001);
+        assert!((progress.fraction - 0.25).abs() < 0.0001);
+        assert_eq!(progress.speed, Some(12.4));
+
+        assert_eq!(parser.feed("speed=N/A"), None);
+        assert_eq!(parser.feed("out_time_us=N/A"), None);
+        let progress = parser.feed("progress=end").expect("final block");
+        assert_eq!(progress.fraction, 1.0);
+        assert_eq!(progress.speed, None);
+    }
+
+    #[test]
+    fn parses_silencedetect_output() {
+        let stderr = "\
+Input #0, mov,mp4,m4a,3gp,3g2,mj2, from 'talk.mp4':
+[silencedetect @ 0x7f9c1c004a40] silence_start: 12.5
+[silencedetect @ 0x7f9c1c004a40] silence_end: 14.25 | silence_duration: 1.75
+[silencedetect @ 0x7f9c1c004a40] silence_start: -0.01
+[silencedetect @ 0x7f9c1c004a40] silence_end: 0.8 | silence_duration: 0.81
+[silencedetect @ 0x7f9c1c004a40] silence_start: 118.2
+size=N/A time=00:02:00.00 bitrate=N/A speed= 512x";
+
+        assert_eq!(
+            parse_silences(stderr, 120.0),
+            vec![
+                SilenceInterval {
+                    start_seconds: 12.5,
+                    end_seconds: 14.25,
+                },
+                SilenceInterval {
+                    start_seconds: 0.0,
+                    end_seconds: 0.8,
+                },
+                SilenceInterval {
+                    start_seconds: 118.2,
+                    end_seconds: 120.0,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn streams_progress_lines_while_running() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = std::env::temp_dir().join("ffmpeg-progress-test.sh");
+        std::fs::write(
+            &script,
+            "#!/bin/sh\nprintf 'out_time_us=1000000\\nprogress=continue\\n'\n\
+             printf 'out_time_us=4000000\\nprogress=end\\n'\necho done >&2\n",
+        )
+        .expect("write fake ffmpeg");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755))
+            .expect("make fake ffmpeg executable");
+
+        let mut updates: Vec<FFmpegProgress> = Vec::new();
+        let output = execute_command_with_progress(
+            script.clone(),
+            &["-i", "input.mp4", "output.mp3"],
+            Duration::from_secs(5),
+            None,
+            4.0,
+            |progress| updates.push(progress),
+        )
+        .await;
+        let _ = std::fs::remove_file(&script);
+
+        let output = output.expect("fake ffmpeg succeeds");
+        assert!(output.command.contains("-progress pipe:1 -nostats -i input.mp4"));
+        assert_eq!(output.stderr.trim(), "done");
+        assert_eq!(updates.len(), 2);
+        assert!((updates[0].fraction - 0.25).abs() < 0.0001);
+        assert_eq!(updates[1].fraction, 1.0);
+    }
+
+    #[test]
+    fn seeks_source_before_input_and_reencodes_audio() {
+        let args = chunk_audio_args(
+            AudioInput {
+                path: "/videos/talk.mkv",
+                stream: Some(2),
+            },
+            1200.25,
+            600.0,
+            &PathBuf::from("/tmp/chunk_003.mp3"),
+        );
+
+        let seek = args.iter().position(|arg| arg == "-ss").expect("seek flag");
+        let input = args.iter().position(|arg| arg == "-i").expect("input flag");
+        assert!(seek < input);
+        assert_eq!(args[seek + 1], "1200.250000");
+        assert!(!args.iter().any(|arg| arg == "copy"));
+        assert!(args.windows(2).any(|pair| pair == ["-map", "0:2"]));
+        assert!(args.windows(2).any(|pair| pair == ["-ar", "16000"]));
+        assert!(args.windows(2).any(|pair| pair == ["-c:a", "libmp3lame"]));
+        assert_eq!(args.last().map(String::as_str), Some("/tmp/chunk_003.mp3"));
+    }
+
+    #[test]
+    fn maps_one_subtitle_stream_to_the_requested_encoder() {
+        let args = subtitle_stream_args(
+            "/videos/talk.mkv",
+            3,
+            "webvtt",
+            &PathBuf::from("/out/talk.de.vtt"),
+        );
+
+        assert!(args.windows(2).any(|pair| pair == ["-map", "0:3"]));
+        assert!(args.windows(2).any(|pair| pair == ["-c:s", "webvtt"]));
+        assert_eq!(args.last().map(String::as_str), Some("/out/talk.de.vtt"));
+    }
+
+    #[test]
+    fn appends_language_tagged_tracks_after_existing_subtitles() {
+        let keep = vec!["0".to_string()];
+        let tracks = [
+            SubtitleTrackInput {
+                path: "/out/talk.srt",
+                codec: "srt",
+                language: Some("eng"),
+                title: Some("English"),
+            },
+            SubtitleTrackInput {
+                path: "/out/talk.de.srt",
+                codec: "srt",
+                language: Some("ger"),
+                title: None,
+            },
+        ];
+        let args = mux_subtitles_args(&SubtitleMux {
+            input: "/videos/talk.mkv",
+            keep_streams: &keep,
+            existing_subtitle_codecs: &[None],
+            tracks: &tracks,
+            output: &PathBuf::from("/out/talk.subtitled.mkv"),
+        });
+
+        let maps: Vec<&str> = args
+            .windows(2)
+            .filter(|pair| pair[0] == "-map")
+            .map(|pair| pair[1].as_str())
+            .collect();
+        assert_eq!(maps, ["0", "1:0", "2:0"]);
+        assert!(args.windows(2).any(|pair| pair == ["-c", "copy"]));
+        assert!(args.windows(2).any(|pair| pair == ["-c:s:1", "srt"]));
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["-metadata:s:s:1", "language=eng"]));
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["-metadata:s:s:2", "language=ger"]));
+        assert!(args
+            .windows(2)
+            .any(|pair| pair == ["-metadata:s:s:1", "title=English"]));
+        assert!(!args.iter().any(|arg| arg == "-c:s"));
+        assert_eq!(
+            args.last().map(String::as_str),
+            Some("/out/talk.subtitled.mkv")
+        );
+    }
+
+    #[test]
+    fn escapes_subtitle_paths_for_the_filtergraph() {
+        assert_eq!(
+            subtitles_filter("/out/talk.srt", "FontName=Arial,Outline=2"),
+            "subtitles=filename=/out/talk.srt:force_style=FontName=Arial\\,Outline=2"
+        );
+        assert_eq!(
+            subtitles_filter("C:\\Videos\\it's [1].srt", "Alignment=2"),
+            "subtitles=filename=C\\\\:/Videos/it\\\\\\'s \\[1\\].srt:force_style=Alignment=2"
+        );
+    }
+
+    #[test]
+    fn burns_in_with_reencode_and_previews_with_original_timestamps() {
+        let burn_in = BurnIn {
+            input: "/videos/talk.mov",
+            subtitle_path: "/out/talk.srt",
+            force_style: "Alignment=2",
+        };
+
+        let args = burn_in_args(&burn_in, &PathBuf::from("/out/talk.burned.mp4"));
+        assert!(args.windows(2).any(|pair| pair == ["-c:v", "libx264"]));
+        assert!(args
+            .windows(2)
+            .any(|pair| pair[0] == "-vf" && pair[1].starts_with("subtitles=")));
+
+        let args = preview_frame_args(&burn_in, 75.5, &PathBuf::from("/tmp/preview.jpg"));
+        let seek = args.iter().position(|arg| arg == "-ss").expect("seek flag");
+        let input = args.iter().position(|arg| arg == "-i").expect("input flag");
+        assert!(seek < input);
+        assert_eq!(args[seek + 1], "75.500");
+        assert!(args.iter().any(|arg| arg == "-copyts"));
+        assert!(args.windows(2).any(|pair| pair == ["-frames:v", "1"]));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs ffmpeg on PATH; run with `cargo test -- --ignored`"]
+    async fn extracted_chunk_starts_on_the_requested_sample() {
+        let dir = std::env::temp_dir().join("ffmpeg-chunk-accuracy-test");
+        std::fs::create_dir_all(&dir).expect("create test dir");
+        let source = dir.join("source.mkv");
+        let chunk = dir.join("chunk.wav");
+
+        // Silence for the first 5 seconds, then a tone: cutting at 4.5s must put the
+        // tone exactly 0.5s (8000 samples at 16 kHz) into the chunk.
+        execute_command(
+            PathBuf::from("ffmpeg"),
+            &[
+                "-y",
+                "-f",
+                "lavfi",
+                "-i",
+                "aevalsrc=if(gte(t\\,5)\\,sin(2*PI*440*t)\\,0):s=48000:d=10",
+                "-f",
+                "lavfi",
+                "-i",
+                "color=c=black:s=64x64:r=25:d=10",
+                "-shortest",
+                "-g",
+                "250",
+                "-c:a",
+                "flac",
+                &source.to_string_lossy(),
+            ],
+            Duration::from_secs(60),
+            None,
+        )
+        .await
+        .expect("generate source video");
+
+        let args = chunk_audio_args(
+            AudioInput {
+                path: &source.to_string_lossy(),
+                stream: None,
+            },
+            4.5,
+            2.0,
+            &chunk,
+        );
+        let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+        execute_command(PathBuf::from("ffmpeg"), &args, Duration::from_secs(60), None)
+            .await
+            .expect("extract chunk audio");
+
+        let wav = std::fs::read(&chunk).expect("read chunk");
+        let _ = std::fs::remove_dir_all(&dir);
+        let data = wav
+            .windows(4)
+            .position(|window| window == b"data")
+            .expect("wav data chunk")
+            + 8;
+        let pcm = wav[data..]
+            .chunks_exact(2)
+            .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+        let first_sound = pcm
+            .iter()
+            .position(|sample| sample.unsigned_abs() > 64)
+            .expect("tone present in chunk");
+
+        assert!(
+            first_sound.abs_diff(8000) <= 32,
+            "tone started at sample {first_sound}"
+        );
+        assert!(pcm.len().abs_diff(32_000) <= 32);
+    }
+}
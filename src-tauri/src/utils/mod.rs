@@ -1 +1,7 @@
+pub mod ass;
 pub mod ffmpeg;
+pub mod retry;
+pub mod srt;
+pub mod subtitle_file;
+pub mod temp;
+pub mod vtt;
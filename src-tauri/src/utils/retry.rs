@@ -0,0 +1,211 @@
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+const BASE_DELAY: Duration = Duration::from_secs(2);
+const MAX_DELAY: Duration = Duration::from_secs(60);
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(5 * 60);
+
+pub trait RetryableError: Display {
+    fn is_retryable(&self) -> bool;
+
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u8) -> Self {
+        Self {
+            max_retries: u32::from(max_retries),
+            base_delay: BASE_DELAY,
+            max_delay: MAX_DELAY,
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_retries + 1
+    }
+
+    /// Delay before retry number `retry` (starting at 1). A server-provided
+    /// `Retry-After` wins over the computed backoff.
+    pub fn delay_for(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(delay) = retry_after {
+            return delay.min(MAX_RETRY_AFTER);
+        }
+
+        let exponent = retry.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        let jitter = backoff.mul_f64(fastrand::f64() * 0.5);
+
+        (backoff + jitter).min(self.max_delay)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryAttempt {
+    pub retry: u32,
+    pub max_retries: u32,
+    pub delay: Duration,
+    pub error: String,
+}
+
+pub async fn retry_with_backoff<T, E, F, Fut, R>(
+    policy: &RetryPolicy,
+    mut operation: F,
+    mut on_retry: R,
+) -> Result<T, E>
+where
+    E: RetryableError,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    R: FnMut(&RetryAttempt),
+{
+    let mut retry = 0;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) if retry < policy.max_retries && error.is_retryable() => {
+                retry += 1;
+                let attempt = RetryAttempt {
+                    retry,
+                    max_retries: policy.max_retries,
+                    delay: policy.delay_for(retry, error.retry_after()),
+                    error: error.to_string(),
+                };
+                eprintln!(
+                    "[retry] attempt {}/{} failed, retrying in {:.1}s: {}",
+                    retry,
+                    policy.max_attempts(),
+                    attempt.delay.as_secs_f64(),
+                    attempt.error
+                );
+
+                on_retry(&attempt);
+                tokio::time::sleep(attempt.delay).await;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::time::Duration;
+
+    use super::{retry_with_backoff, RetryPolicy, RetryableError};
+
+    #[derive(Debug)]
+    struct TestError {
+        retryable: bool,
+    }
+
+    impl fmt::Display for TestError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "retryable: {}", self.retryable)
+        }
+    }
+
+    impl RetryableError for TestError {
+        fn is_retryable(&self) -> bool {
+            self.retryable
+        }
+    }
+
+    fn fast_policy(max_retries: u8) -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            ..RetryPolicy::new(max_retries)
+        }
+    }
+
+    #[test]
+    fn backs_off_exponentially_within_bounds() {
+        let policy = RetryPolicy::new(5);
+        for retry in 1..=5 {
+            let base = (policy.base_delay * (1 << (retry - 1))).min(policy.max_delay);
+            let delay = policy.delay_for(retry, None);
+            assert!(delay >= base, "retry {retry}: {delay:?} < {base:?}");
+            assert!(delay <= policy.max_delay);
+            assert!(delay <= base.mul_f64(1.5));
+        }
+    }
+
+    #[test]
+    fn prefers_retry_after_over_backoff() {
+        let policy = RetryPolicy::new(3);
+        assert_eq!(
+            policy.delay_for(1, Some(Duration::from_secs(17))),
+            Duration::from_secs(17)
+        );
+        assert_eq!(
+            policy.delay_for(1, Some(Duration::from_secs(3600))),
+            Duration::from_secs(300)
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures_until_success() {
+        let mut calls = 0;
+        let mut retries = Vec::new();
+        let result = retry_with_backoff(
+            &fast_policy(3),
+            || {
+                calls += 1;
+                let outcome = if calls < 3 {
+                    Err(TestError { retryable: true })
+                } else {
+                    Ok(calls)
+                };
+                async move { outcome }
+            },
+            |attempt| retries.push(attempt.retry),
+        )
+        .await;
+
+        assert_eq!(result.expect("third attempt succeeds"), 3);
+        assert_eq!(retries, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn stops_on_fatal_errors_and_exhausted_retries() {
+        let mut calls = 0;
+        let fatal: Result<(), _> = retry_with_backoff(
+            &fast_policy(3),
+            || {
+                calls += 1;
+                async { Err(TestError { retryable: false }) }
+            },
+            |_| {},
+        )
+        .await;
+        assert!(fatal.is_err());
+        assert_eq!(calls, 1);
+
+        let mut calls = 0;
+        let exhausted: Result<(), _> = retry_with_backoff(
+            &fast_policy(2),
+            || {
+                calls += 1;
+                async { Err(TestError { retryable: true }) }
+            },
+            |_| {},
+        )
+        .await;
+        assert!(exhausted.is_err());
+        assert_eq!(calls, 3);
+    }
+}
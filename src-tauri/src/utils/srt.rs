@@ -0,0 +1,378 @@
+use crate::commands::video::ChunkInfo;
+use crate::models::transcript::{offset_words, Segment};
+use crate::utils::subtitle_file::UTF8_BOM;
+
+const TIMESTAMP_SEPARATOR: &str = " --> ";
+const SEAM_TIME_TOLERANCE_SECONDS: f64 = 1.0;
+const SEAM_TEXT_SIMILARITY: f64 = 0.6;
+
+pub fn format_timestamp(seconds: f64) -> String {
+    let total_millis = if seconds.is_finite() && seconds > 0.0 {
+        (seconds * 1000.0).round() as u64
+    } else {
+        0
+    };
+
+    let hours = total_millis / 3_600_000;
+    let minutes = (total_millis % 3_600_000) / 60_000;
+    let secs = (total_millis % 60_000) / 1000;
+    let millis = total_millis % 1000;
+
+    format!("{hours:02}:{minutes:02}:{secs:02},{millis:03}")
+}
+
+pub fn parse_timestamp(value: &str) -> Result<f64, String> {
+    let invalid = || format!("Invalid SRT timestamp: {value}");
+    let (clock, millis) = value.trim().split_once(',').ok_or_else(invalid)?;
+    let parts = clock
+        .split(':')
+        .map(|part| part.parse::<u64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let [hours, minutes, seconds] = parts[..] else {
+        return Err(invalid());
+    };
+
+    if minutes >= 60 || seconds >= 60 || millis.len() != 3 {
+        return Err(invalid());
+    }
+
+    let millis = millis.parse::<u64>().map_err(|_| invalid())?;
+    let total_millis = ((hours * 60 + minutes) * 60 + seconds) * 1000 + millis;
+
+    Ok(total_millis as f64 / 1000.0)
+}
+
+fn normalize_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn comparable_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+// Share of the shorter text's words that also appear in the longer one, so a
+// half-sentence cut at a chunk edge still matches its complete counterpart.
+fn text_similarity(left: &str, right: &str) -> f64 {
+    let left = comparable_words(left);
+    let right = comparable_words(right);
+    let (shorter, longer) = if left.len() <= right.len() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    if shorter.is_empty() {
+        return 0.0;
+    }
+
+    let shared = shorter.iter().filter(|word| longer.contains(word)).count();
+    shared as f64 / shorter.len() as f64
+}
+
+fn is_seam_duplicate(existing: &Segment, start: f64, end: f64, text: &str) -> bool {
+    let overlaps_in_time = start <= existing.end + SEAM_TIME_TOLERANCE_SECONDS
+        && existing.start <= end + SEAM_TIME_TOLERANCE_SECONDS;
+
+    overlaps_in_time && text_similarity(&existing.text, text) >= SEAM_TEXT_SIMILARITY
+}
+
+pub fn merge_chunk_segments(
+    chunks: &[ChunkInfo],
+    transcripts: &[Vec<Segment>],
+) -> Result<Vec<Segment>, String> {
+    if chunks.len() != transcripts.len() {
+        return Err(format!(
+            "Expected transcripts for {} chunks but received {}.",
+            chunks.len(),
+            transcripts.len()
+        ));
+    }
+
+    let mut merged: Vec<Segment> = Vec::new();
+
+    for (index, (chunk, segments)) in chunks.iter().zip(transcripts).enumerate() {
+        let seam_end = index
+            .checked_sub(1)
+            .map(|previous| chunks[previous].padded_end_seconds)
+            .unwrap_or(chunk.padded_start_seconds);
+
+        for segment in segments {
+            let text = normalize_text(&segment.text);
+            if text.is_empty() || !segment.start.is_finite() || !segment.end.is_finite() {
+                continue;
+            }
+
+            let start = chunk.padded_start_seconds + segment.start.max(0.0);
+            if start >= chunk.padded_end_seconds {
+                continue;
+            }
+            let end = (chunk.padded_start_seconds + segment.end)
+                .min(chunk.padded_end_seconds)
+                .max(start);
+
+            if start < seam_end {
+                let duplicate = merged
+                    .iter()
+                    .rposition(|existing| is_seam_duplicate(existing, start, end, &text));
+
+                if let Some(position) = duplicate {
+                    let is_last = position + 1 == merged.len();
+                    let existing = &mut merged[position];
+                    if comparable_words(&text).len() > comparable_words(&existing.text).len() {
+                        existing.text = text;
+                        existing.words =
+                            offset_words(&segment.words, chunk.padded_start_seconds, start, end);
+                        if is_last {
+                            existing.end = existing.end.max(end);
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            let previous_end = merged.last().map(|last| last.end).unwrap_or(0.0);
+            let start = start.max(previous_end);
+            let end = end.max(start);
+            merged.push(Segment {
+                start,
+                end,
+                text,
+                words: offset_words(&segment.words, chunk.padded_start_seconds, start, end),
+            });
+        }
+    }
+
+    Ok(merged)
+}
+
+pub fn generate_srt(segments: &[Segment]) -> String {
+    let mut output = String::new();
+
+    for (index, segment) in segments.iter().enumerate() {
+        output.push_str(&format!(
+            "{}\n{}{TIMESTAMP_SEPARATOR}{}\n{}\n\n",
+            index + 1,
+            format_timestamp(segment.start),
+            format_timestamp(segment.end),
+            normalize_text(&segment.text)
+        ));
+    }
+
+    output
+}
+
+pub fn parse_srt(content: &str) -> Result<Vec<Segment>, String> {
+    let content = content.trim_start_matches(UTF8_BOM).replace("\r\n", "\n");
+    let mut segments = Vec::new();
+
+    for block in content
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+    {
+        let mut lines = block.trim().lines();
+        let index = lines.next().unwrap_or_default();
+        index
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid SRT cue index: {index}"))?;
+
+        let timing = lines
+            .next()
+            .ok_or_else(|| format!("Missing timing line for SRT cue {index}"))?;
+        let (start, end) = timing
+            .split_once(TIMESTAMP_SEPARATOR)
+            .ok_or_else(|| format!("Invalid SRT timing line: {timing}"))?;
+
+        segments.push(Segment {
+            start: parse_timestamp(start)?,
+            end: parse_timestamp(end)?,
+            text: lines.collect::<Vec<_>>().join("\n"),
+            words: Vec::new(),
+        });
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_timestamp, generate_srt, merge_chunk_segments, parse_srt, parse_timestamp};
+    use crate::commands::video::{calculate_chunks_for_duration, calculate_chunks_with_overlap};
+    use crate::models::transcript::{Segment, Word};
+
+    fn segment(start: f64, end: f64, text: &str) -> Segment {
+        Segment {
+            start,
+            end,
+            text: text.to_string(),
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn formats_timestamps_with_millisecond_precision() {
+        assert_eq!(format_timestamp(0.0), "00:00:00,000");
+        assert_eq!(format_timestamp(3661.5), "01:01:01,500");
+        assert_eq!(format_timestamp(59.9996), "00:01:00,000");
+        assert_eq!(format_timestamp(-3.0), "00:00:00,000");
+        assert_eq!(format_timestamp(36_000.042), "10:00:00,042");
+    }
+
+    #[test]
+    fn rejects_malformed_timestamps() {
+        assert!(parse_timestamp("00:00:01.000").is_err());
+        assert!(parse_timestamp("00:61:00,000").is_err());
+        assert!(parse_timestamp("00:00,000").is_err());
+        assert!((parse_timestamp("01:02:03,004").unwrap() - 3723.004).abs() < 0.0001);
+    }
+
+    #[test]
+    fn round_trips_generated_srt() {
+        let segments = vec![
+            segment(0.0, 2.5, "First line"),
+            segment(2.5, 5.125, "Second line\nwith a break"),
+            segment(3600.0, 3601.999, "An hour in"),
+        ];
+
+        let content = generate_srt(&segments);
+        assert!(content.starts_with("1\n00:00:00,000 --> 00:00:02,500\nFirst line\n\n2\n"));
+
+        let parsed = parse_srt(&format!("\u{feff}{content}")).expect("generated SRT should parse");
+        assert_eq!(parsed, segments);
+        assert_eq!(generate_srt(&parsed), content);
+    }
+
+    #[test]
+    fn offsets_and_renumbers_segments_across_chunks() {
+        let chunks = calculate_chunks_for_duration(1500.0, 10).expect("chunks");
+        let transcripts = vec![
+            vec![segment(0.0, 4.0, "one"), segment(598.0, 600.0, "two")],
+            vec![segment(1.0, 3.0, "three")],
+            vec![segment(0.5, 2.0, "four")],
+        ];
+
+        let merged = merge_chunk_segments(&chunks, &transcripts).expect("merge");
+        assert_eq!(merged.len(), 4);
+        assert!((merged[2].start - 601.0).abs() < 0.001);
+        assert!((merged[3].start - 1200.5).abs() < 0.001);
+
+        let parsed = parse_srt(&generate_srt(&merged)).expect("parse");
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[3].text, "four");
+    }
+
+    #[test]
+    fn carries_word_timestamps_through_the_merge() {
+        let chunks = calculate_chunks_for_duration(1200.0, 10).expect("chunks");
+        let mut second = segment(1.0, 3.0, "hello world");
+        second.words = vec![
+            Word {
+                start: 1.2,
+                end: 1.8,
+                text: "hello".to_string(),
+            },
+            Word {
+                start: 2.0,
+                end: 3.4,
+                text: "world".to_string(),
+            },
+        ];
+        let transcripts = vec![vec![segment(0.0, 1.0, "first")], vec![second]];
+
+        let merged = merge_chunk_segments(&chunks, &transcripts).expect("merge");
+        assert!(merged[0].words.is_empty());
+        assert!((merged[1].words[0].start - 601.2).abs() < 0.001);
+        assert!((merged[1].words[1].end - 603.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn skips_empty_chunks_and_blank_segments() {
+        let chunks = calculate_chunks_for_duration(1800.0, 10).expect("chunks");
+        let transcripts = vec![
+            vec![segment(0.0, 1.0, "hello")],
+            Vec::new(),
+            vec![segment(0.0, 1.0, "   "), segment(2.0, 3.0, "world")],
+        ];
+
+        let merged = merge_chunk_segments(&chunks, &transcripts).expect("merge");
+        assert_eq!(merged.len(), 2);
+        assert!((merged[1].start - 1202.0).abs() < 0.001);
+        assert!(generate_srt(&merged).starts_with("1\n"));
+        assert!(generate_srt(&merged).contains("\n\n2\n"));
+    }
+
+    #[test]
+    fn clamps_segments_that_run_past_chunk_end() {
+        let chunks = calculate_chunks_for_duration(1500.0, 10).expect("chunks");
+        let transcripts = vec![
+            vec![segment(595.0, 607.0, "overrun")],
+            vec![segment(0.0, 2.0, "next")],
+            vec![
+                segment(299.0, 310.0, "tail"),
+                segment(305.0, 306.0, "beyond"),
+            ],
+        ];
+
+        let merged = merge_chunk_segments(&chunks, &transcripts).expect("merge");
+        assert_eq!(merged.len(), 3);
+        assert!((merged[0].end - 600.0).abs() < 0.001);
+        assert!((merged[1].start - 600.0).abs() < 0.001);
+        assert!((merged[2].end - 1500.0).abs() < 0.001);
+        assert!(merged.windows(2).all(|pair| pair[0].end <= pair[1].start));
+    }
+
+    #[test]
+    fn drops_segments_duplicated_in_chunk_overlap() {
+        let chunks = calculate_chunks_with_overlap(1200.0, 10, 10.0).expect("chunks");
+        let transcripts = vec![
+            vec![
+                segment(580.0, 594.0, "We talked about the roadmap."),
+                segment(595.0, 601.5, "And then we went to"),
+                segment(602.0, 608.0, "Questions from the audience."),
+            ],
+            vec![
+                segment(0.0, 4.0, "about the roadmap."),
+                segment(5.0, 13.0, "And then we went to the store together."),
+                segment(13.0, 18.0, "Questions from the audience."),
+                segment(20.0, 24.0, "New material."),
+            ],
+        ];
+
+        let merged = merge_chunk_segments(&chunks, &transcripts).expect("merge");
+        let texts = merged
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            texts,
+            vec![
+                "We talked about the roadmap.",
+                "And then we went to the store together.",
+                "Questions from the audience.",
+                "New material.",
+            ]
+        );
+        assert!((merged[3].start - 610.0).abs() < 0.001);
+        assert!(merged.windows(2).all(|pair| pair[0].end <= pair[1].start));
+    }
+
+    #[test]
+    fn rejects_mismatched_transcript_count() {
+        let chunks = calculate_chunks_for_duration(1200.0, 10).expect("chunks");
+        assert!(merge_chunk_segments(&chunks, &[Vec::new()]).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use tokio::fs;
+
+use crate::models::settings::SubtitleFormat;
+
+pub const UTF8_BOM: &str = "\u{feff}";
+
+// Windows players guess the encoding of SRT and ASS files without one, while WebVTT
+// validators on web platforms tend to reject the file when it starts with one.
+fn byte_order_mark(format: SubtitleFormat) -> &'static str {
+    match format {
+        SubtitleFormat::Srt | SubtitleFormat::Ass => UTF8_BOM,
+        SubtitleFormat::Vtt => "",
+    }
+}
+
+pub async fn write_subtitle_file(
+    path: &Path,
+    format: SubtitleFormat,
+    content: &str,
+) -> Result<(), String> {
+    fs::write(path, format!("{}{content}", byte_order_mark(format)))
+        .await
+        .map_err(|error| format!("Failed to write subtitle file {}: {error}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_order_mark, UTF8_BOM};
+    use crate::models::settings::SubtitleFormat;
+
+    #[test]
+    fn writes_a_bom_for_srt_and_ass_only() {
+        assert_eq!(byte_order_mark(SubtitleFormat::Srt), UTF8_BOM);
+        assert_eq!(byte_order_mark(SubtitleFormat::Ass), UTF8_BOM);
+        assert_eq!(byte_order_mark(SubtitleFormat::Vtt), "");
+    }
+}
@@ -0,0 +1,45 @@
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "gui")]
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+
+#[cfg(feature = "gui")]
+const JOBS_DIR_NAME: &str = "jobs";
+
+#[cfg(feature = "gui")]
+pub fn jobs_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let mut dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|error| format!("Failed to resolve app cache directory: {error}"))?;
+
+    dir.push(JOBS_DIR_NAME);
+    Ok(dir)
+}
+
+pub async fn create_job_dir(root: &Path, job_id: &str) -> Result<PathBuf, String> {
+    let dir = root.join(job_id);
+
+    fs::create_dir_all(&dir)
+        .await
+        .map_err(|error| format!("Failed to create temp directory {}: {error}", dir.display()))?;
+
+    Ok(dir)
+}
+
+pub async fn cleanup_job_dir(dir: &Path) {
+    match fs::remove_dir_all(dir).await {
+        Ok(()) => eprintln!("[temp] removed {}", dir.display()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => eprintln!("[temp] failed to remove {}: {error}", dir.display()),
+    }
+}
+
+pub async fn remove_file(path: &Path) {
+    if let Err(error) = fs::remove_file(path).await {
+        if error.kind() != std::io::ErrorKind::NotFound {
+            eprintln!("[temp] failed to remove {}: {error}", path.display());
+        }
+    }
+}
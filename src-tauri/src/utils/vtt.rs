@@ -0,0 +1,169 @@
+use crate::models::style::{HorizontalAlignment, SubtitlePosition, SubtitleStyle};
+use crate::models::transcript::Segment;
+
+const TIMESTAMP_SEPARATOR: &str = " --> ";
+
+pub struct VttOptions<'a> {
+    /// Written as a `Language:` header when set.
+    pub language: Option<&'a str>,
+    /// Turned into `line`/`align` cue settings when it differs from bottom-centre.
+    pub style: Option<&'a SubtitleStyle>,
+    /// One `NOTE` line each, e.g. the source file and provider.
+    pub notes: &'a [String],
+}
+
+/// `HH:MM:SS.mmm`.
+pub fn format_timestamp(seconds: f64) -> String {
+    let total_millis = if seconds.is_finite() && seconds > 0.0 {
+        (seconds * 1000.0).round() as u64
+    } else {
+        0
+    };
+
+    let hours = total_millis / 3_600_000;
+    let minutes = (total_millis % 3_600_000) / 60_000;
+    let secs = (total_millis % 60_000) / 1000;
+    let millis = total_millis % 1000;
+
+    format!("{hours:02}:{minutes:02}:{secs:02}.{millis:03}")
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// A blank line ends a cue or note, and `-->` would start a new one.
+fn cue_text(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(escape_text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn note_text(note: &str) -> String {
+    note.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("-->", "->")
+}
+
+fn cue_settings(style: &SubtitleStyle) -> String {
+    let mut settings = Vec::new();
+    match style.position {
+        SubtitlePosition::Top => settings.push("line:0"),
+        SubtitlePosition::Middle => settings.push("line:50%,center"),
+        SubtitlePosition::Bottom => {}
+    }
+    match style.alignment {
+        HorizontalAlignment::Left => settings.push("align:start"),
+        HorizontalAlignment::Right => settings.push("align:end"),
+        HorizontalAlignment::Center => {}
+    }
+
+    settings
+        .iter()
+        .map(|setting| format!(" {setting}"))
+        .collect()
+}
+
+pub fn generate_vtt(segments: &[Segment], options: &VttOptions) -> String {
+    let mut output = String::from("WEBVTT\n");
+    if let Some(language) = options
+        .language
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+    {
+        output.push_str(&format!("Language: {language}\n"));
+    }
+    output.push('\n');
+
+    let notes: Vec<String> = options
+        .notes
+        .iter()
+        .map(|note| note_text(note))
+        .filter(|note| !note.is_empty())
+        .collect();
+    if !notes.is_empty() {
+        output.push_str(&format!("NOTE\n{}\n\n", notes.join("\n")));
+    }
+
+    let settings = options.style.map(cue_settings).unwrap_or_default();
+    for (index, segment) in segments.iter().enumerate() {
+        output.push_str(&format!(
+            "{}\n{}{TIMESTAMP_SEPARATOR}{}{settings}\n{}\n\n",
+            index + 1,
+            format_timestamp(segment.start),
+            format_timestamp(segment.end),
+            cue_text(&segment.text)
+        ));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_timestamp, generate_vtt, VttOptions};
+    use crate::models::style::{HorizontalAlignment, SubtitlePosition, SubtitleStyle};
+    use crate::models::transcript::Segment;
+
+    fn segment(start: f64, end: f64, text: &str) -> Segment {
+        Segment {
+            start,
+            end,
+            text: text.to_string(),
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn formats_timestamps_with_a_dot() {
+        assert_eq!(format_timestamp(3723.4567), "01:02:03.457");
+        assert_eq!(format_timestamp(-2.0), "00:00:00.000");
+    }
+
+    #[test]
+    fn writes_header_notes_and_numbered_cues() {
+        let notes = vec!["Source: talk.mp4".to_string(), "Odd --> note".to_string()];
+        let options = VttOptions {
+            language: Some("de"),
+            style: Some(&SubtitleStyle::default()),
+            notes: &notes,
+        };
+        let segments = vec![
+            segment(0.0, 1.5, "Hallo <Welt> & so"),
+            segment(2.0, 3.0, "Zwei\n\nZeilen"),
+        ];
+
+        assert_eq!(
+            generate_vtt(&segments, &options),
+            "WEBVTT\nLanguage: de\n\nNOTE\nSource: talk.mp4\nOdd -> note\n\n\
+             1\n00:00:00.000 --> 00:00:01.500\nHallo &lt;Welt&gt; &amp; so\n\n\
+             2\n00:00:02.000 --> 00:00:03.000\nZwei\nZeilen\n\n"
+        );
+    }
+
+    #[test]
+    fn adds_cue_settings_for_positioned_styles() {
+        let style = SubtitleStyle {
+            position: SubtitlePosition::Top,
+            alignment: HorizontalAlignment::Left,
+            ..SubtitleStyle::default()
+        };
+        let options = VttOptions {
+            language: None,
+            style: Some(&style),
+            notes: &[],
+        };
+
+        let output = generate_vtt(&[segment(1.0, 2.0, "Top")], &options);
+        assert!(output.starts_with("WEBVTT\n\n1\n"));
+        assert!(output.contains("00:00:01.000 --> 00:00:02.000 line:0 align:start\nTop\n"));
+    }
+}
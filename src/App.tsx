@@ -1,19 +1,45 @@
 import { useEffect, useState } from "react";
+import { BurnInPanel } from "./components/BurnInPanel";
 import { CostEstimate } from "./components/CostEstimate";
 import { FileSelector } from "./components/FileSelector";
 import { LanguageSelector } from "./components/LanguageSelector";
 import { OutputLocationPicker } from "./components/OutputLocationPicker";
+import { QueuePanel } from "./components/QueuePanel";
 import { SettingsModal } from "./components/SettingsModal";
+import { WatchFoldersPanel } from "./components/WatchFoldersPanel";
 import {
   calculateChunks,
+  cancelProcessing,
+  discardJob,
   estimateCost,
+  extractEmbeddedSubtitles,
   getSettings,
   getSupportedLanguages,
   getVideoInfo,
+  listUnfinishedJobs,
+  muxSubtitles,
+  onProcessingProgress,
+  onProcessingRetry,
+  processVideo,
+  resumeJob,
+  selectSubtitleFiles,
 } from "./services/tauri-commands";
 import { WHISPER_LANGUAGES, type Language } from "./types/languages";
-import type { ChunkInfo, CostEstimate as CostEstimateModel, VideoInfo } from "./types/processing";
-import type { AppSettings } from "./types/settings";
+import type {
+  AudioStreamInfo,
+  ChunkInfo,
+  CostEstimate as CostEstimateModel,
+  JobSummary,
+  MuxContainer,
+  MuxTrack,
+  ProcessingProgress,
+  ProcessingResult,
+  ProcessingRetry,
+  VideoInfo,
+} from "./types/processing";
+import type { JobOverrides } from "./types/queue";
+import type { AppSettings, SubtitleFormat } from "./types/settings";
+import { DEFAULT_SUBTITLE_STYLE } from "./types/style";
 import type { VideoFileInfo } from "./types/video";
 import "./App.css";
 
@@ -47,6 +73,18 @@ function formatDuration(totalSeconds: number): string {
   return [hours, minutes, seconds].map((value) => String(value).padStart(2, "0")).join(":");
 }
 
+function formatAudioStream(stream: AudioStreamInfo): string {
+  const details = [
+    stream.language ?? "unknown language",
+    stream.codecName,
+    stream.channels ? `${stream.channels} ch` : null,
+    stream.isDefault ? "default" : null,
+  ].filter(Boolean);
+  const title = stream.title ? `${stream.title} ` : "";
+
+  return `#${stream.index} ${title}(${details.join(", ")})`;
+}
+
 function App() {
   const [isSettingsOpen, setIsSettingsOpen] = useState(false);
   const [settings, setSettings] = useState<AppSettings | null>(null);
@@ -54,15 +92,42 @@ function App() {
   const [selectedVideo, setSelectedVideo] = useState<VideoFileInfo | null>(null);
   const [sourceLanguage, setSourceLanguage] = useState<string>("en");
   const [targetLanguage, setTargetLanguage] = useState<string | null>(null);
+  const [outputFormat, setOutputFormat] = useState<SubtitleFormat>("srt");
   const [outputFolderOverride, setOutputFolderOverride] = useState<string | null>(null);
   const [loadError, setLoadError] = useState<string | null>(null);
 
   const [videoInfo, setVideoInfo] = useState<VideoInfo | null>(null);
+  const [audioStreamIndex, setAudioStreamIndex] = useState<number | null>(null);
   const [chunks, setChunks] = useState<ChunkInfo[]>([]);
+  const [chunkNotice, setChunkNotice] = useState<string | null>(null);
   const [costEstimate, setCostEstimate] = useState<CostEstimateModel | null>(null);
   const [analysisLoading, setAnalysisLoading] = useState(false);
   const [analysisError, setAnalysisError] = useState<string | null>(null);
 
+  const [isProcessing, setIsProcessing] = useState(false);
+  const [progress, setProgress] = useState<ProcessingProgress | null>(null);
+  const [retryNotice, setRetryNotice] = useState<ProcessingRetry | null>(null);
+  const [processingResult, setProcessingResult] = useState<ProcessingResult | null>(null);
+  const [processingError, setProcessingError] = useState<string | null>(null);
+  const [embeddedStreamIndex, setEmbeddedStreamIndex] = useState<number | null>(null);
+  const [embeddedFormat, setEmbeddedFormat] = useState<SubtitleFormat>("srt");
+  const [isExtracting, setIsExtracting] = useState(false);
+  const [extractedPath, setExtractedPath] = useState<string | null>(null);
+  const [muxTracks, setMuxTracks] = useState<MuxTrack[]>([]);
+  const [muxContainer, setMuxContainer] = useState<MuxContainer | null>(null);
+  const [isMuxing, setIsMuxing] = useState(false);
+  const [muxedPath, setMuxedPath] = useState<string | null>(null);
+  const [isBurningIn, setIsBurningIn] = useState(false);
+  const [unfinishedJobs, setUnfinishedJobs] = useState<JobSummary[]>([]);
+
+  const refreshUnfinishedJobs = async () => {
+    try {
+      setUnfinishedJobs(await listUnfinishedJobs());
+    } catch (error) {
+      console.error("Failed to load unfinished jobs", error);
+    }
+  };
+
   useEffect(() => {
     const load = async () => {
       try {
@@ -75,6 +140,7 @@ function App() {
         setSourceLanguage(loadedSettings.sourceLanguage);
         setTargetLanguage(loadedSettings.targetLanguage);
         setOutputFolderOverride(loadedSettings.defaultOutputFolder);
+        setOutputFormat(loadedSettings.defaultOutputFormat);
       } catch (error) {
         console.error("Failed to load settings", error);
         setLoadError(error instanceof Error ? error.message : "Failed to load app settings.");
@@ -82,12 +148,28 @@ function App() {
     };
 
     void load();
+    void refreshUnfinishedJobs();
+  }, []);
+
+  useEffect(() => {
+    const subscriptions = [
+      onProcessingProgress((nextProgress) => {
+        setProgress(nextProgress);
+        setRetryNotice((notice) => (notice?.chunkNumber === nextProgress.chunkNumber ? notice : null));
+      }),
+      onProcessingRetry(setRetryNotice),
+    ];
+
+    return () => {
+      subscriptions.forEach((subscription) => void subscription.then((unlisten) => unlisten()));
+    };
   }, []);
 
   useEffect(() => {
     if (!selectedVideo) {
       setVideoInfo(null);
       setChunks([]);
+      setChunkNotice(null);
       setCostEstimate(null);
       setAnalysisError(null);
       setAnalysisLoading(false);
@@ -95,6 +177,9 @@ function App() {
     }
 
     let cancelled = false;
+    setAudioStreamIndex(null);
+    setEmbeddedStreamIndex(null);
+    setExtractedPath(null);
 
     const analyzeVideo = async () => {
       setAnalysisLoading(true);
@@ -102,7 +187,7 @@ function App() {
       try {
         const info = await getVideoInfo(selectedVideo.path);
         const chunkDuration = settings?.chunkDurationMinutes ?? 20;
-        const [chunkList, estimate] = await Promise.all([
+        const [chunkPlan, estimate] = await Promise.all([
           calculateChunks(info.durationSeconds, chunkDuration),
           estimateCost(info.durationSeconds),
         ]);
@@ -110,7 +195,8 @@ function App() {
         if (cancelled) return;
 
         setVideoInfo(info);
-        setChunks(chunkList);
+        setChunks(chunkPlan.chunks);
+        setChunkNotice(chunkPlan.notice);
         setCostEstimate(estimate);
       } catch (error) {
         if (cancelled) return;
@@ -118,6 +204,7 @@ function App() {
         setAnalysisError(message);
         setVideoInfo(null);
         setChunks([]);
+        setChunkNotice(null);
         setCostEstimate(null);
       } finally {
         if (!cancelled) {
@@ -131,7 +218,15 @@ function App() {
     return () => {
       cancelled = true;
     };
-  }, [selectedVideo, settings?.chunkDurationMinutes]);
+  }, [
+    selectedVideo,
+    settings?.chunkDurationMinutes,
+    settings?.chunkingMode,
+    settings?.chunkOverlapSeconds,
+    settings?.silenceWindowSeconds,
+    settings?.provider,
+    settings?.compatiblePricePerMinuteUsd,
+  ]);
 
   const availableLanguages = (() => {
     const list = allLanguages.length > 0 ? allLanguages : WHISPER_LANGUAGES;
@@ -142,10 +237,129 @@ function App() {
     return list.filter((language) => preferred.has(language.code));
   })();
 
+  const largestChunkBytes = chunks.reduce((largest, chunk) => Math.max(largest, chunk.expectedSizeBytes), 0);
+
   const defaultOutputFolder = selectedVideo ? parentFolder(selectedVideo.path) : null;
   const effectiveOutputFolder = outputFolderOverride ?? defaultOutputFolder;
-  const canProcess = Boolean(selectedVideo && videoInfo && !analysisLoading && !analysisError);
-  const isProcessing = false;
+  const canProcess = Boolean(
+    selectedVideo &&
+      videoInfo &&
+      !analysisLoading &&
+      !analysisError &&
+      !isProcessing &&
+      !isExtracting &&
+      !isMuxing &&
+      !isBurningIn,
+  );
+  const activeStylePreset = settings?.stylePresets.find((preset) => preset.name === settings.activeStylePreset);
+  const activeStyle = activeStylePreset?.style ?? DEFAULT_SUBTITLE_STYLE;
+  const textSubtitleStreams = videoInfo?.subtitleStreams.filter((stream) => stream.isText) ?? [];
+  const selectedEmbeddedStream = embeddedStreamIndex ?? textSubtitleStreams[0]?.index ?? null;
+
+  const jobOverrides: JobOverrides = {
+    sourceLanguage,
+    targetLanguage,
+    outputFolder: outputFolderOverride,
+    outputFormat,
+    audioStreamIndex: null,
+  };
+
+  const runJob = async (run: () => Promise<ProcessingResult>) => {
+    setIsProcessing(true);
+    setProgress(null);
+    setRetryNotice(null);
+    setProcessingResult(null);
+    setProcessingError(null);
+    try {
+      setProcessingResult(await run());
+    } catch (error) {
+      setProcessingError(error instanceof Error ? error.message : "Processing failed.");
+    } finally {
+      setIsProcessing(false);
+      void refreshUnfinishedJobs();
+    }
+  };
+
+  const handleProcess = async () => {
+    if (!selectedVideo) return;
+
+    setMuxedPath(null);
+    await runJob(async () => {
+      const result = await processVideo({
+        videoPath: selectedVideo.path,
+        sourceLanguage,
+        targetLanguage,
+        outputFolder: outputFolderOverride,
+        outputFormat,
+        audioStreamIndex,
+      });
+      setMuxTracks([
+        { path: result.outputPath, language: result.outputLanguage },
+        ...(result.translatedOutputPath ? [{ path: result.translatedOutputPath, language: targetLanguage }] : []),
+      ]);
+      return result;
+    });
+  };
+
+  const handleAddSubtitleFiles = async () => {
+    try {
+      const selected = await selectSubtitleFiles();
+      setMuxTracks((tracks) => [
+        ...tracks,
+        ...selected.filter((track) => !tracks.some((existing) => existing.path === track.path)),
+      ]);
+    } catch (error) {
+      setProcessingError(error instanceof Error ? error.message : "Failed to select subtitle files.");
+    }
+  };
+
+  const handleMux = async () => {
+    if (!selectedVideo || muxTracks.length === 0) return;
+
+    setIsMuxing(true);
+    setMuxedPath(null);
+    setProgress(null);
+    setProcessingError(null);
+    try {
+      setMuxedPath(await muxSubtitles(selectedVideo.path, muxTracks, muxContainer, outputFolderOverride));
+    } catch (error) {
+      setProcessingError(error instanceof Error ? error.message : "Failed to add subtitle tracks.");
+    } finally {
+      setIsMuxing(false);
+    }
+  };
+
+  const handleExtractSubtitles = async () => {
+    if (!selectedVideo || selectedEmbeddedStream === null) return;
+
+    setIsExtracting(true);
+    setExtractedPath(null);
+    setProcessingResult(null);
+    setProcessingError(null);
+    try {
+      setExtractedPath(
+        await extractEmbeddedSubtitles(
+          selectedVideo.path,
+          selectedEmbeddedStream,
+          embeddedFormat,
+          outputFolderOverride,
+        ),
+      );
+    } catch (error) {
+      setProcessingError(error instanceof Error ? error.message : "Failed to extract subtitles.");
+    } finally {
+      setIsExtracting(false);
+    }
+  };
+
+  const handleDiscard = async (jobId: string) => {
+    try {
+      await discardJob(jobId);
+    } catch (error) {
+      setProcessingError(error instanceof Error ? error.message : "Failed to discard job.");
+    }
+    void refreshUnfinishedJobs();
+  };
 
   return (
     <main className="app-shell">
@@ -161,6 +375,38 @@ function App() {
 
       {loadError && <p className="error-text">{loadError}</p>}
 
+      {unfinishedJobs.length > 0 && (
+        <section className="card">
+          <div className="section-header">
+            <h2>Unfinished Jobs</h2>
+          </div>
+          {unfinishedJobs.map((job) => (
+            <div key={job.id} className="job-row">
+              <p className="info-text">
+                {job.inputPath} ({job.completedChunks}/{job.totalChunks} chunks done)
+                {job.lastError && ` - ${job.lastError}`}
+              </p>
+              <button
+                type="button"
+                className="primary-btn"
+                disabled={isProcessing}
+                onClick={() => void runJob(() => resumeJob(job.id))}
+              >
+                Resume
+              </button>
+              <button
+                type="button"
+                className="secondary-btn"
+                disabled={isProcessing}
+                onClick={() => void handleDiscard(job.id)}
+              >
+                Discard
+              </button>
+            </div>
+          ))}
+        </section>
+      )}
+
       <FileSelector selectedFile={selectedVideo} onFileChange={setSelectedVideo} />
 
       <section className="card">
@@ -189,6 +435,8 @@ function App() {
         outputFolder={effectiveOutputFolder}
         fallbackLabel="Same folder as selected video"
         onChange={setOutputFolderOverride}
+        outputFormat={outputFormat}
+        onFormatChange={setOutputFormat}
       />
 
       <section className="card">
@@ -216,6 +464,10 @@ function App() {
               <dt>Chunks</dt>
               <dd>{chunks.length}</dd>
             </div>
+            <div>
+              <dt>Largest Upload</dt>
+              <dd>{formatBytes(largestChunkBytes)}</dd>
+            </div>
             <div>
               <dt>Format / Codec</dt>
               <dd>
@@ -228,11 +480,54 @@ function App() {
                 {videoInfo.width && videoInfo.height
                   ? `${videoInfo.width}x${videoInfo.height}`
                   : "Unavailable"}
+                {videoInfo.rotation !== 0 && ` (rotated ${videoInfo.rotation}°)`}
+              </dd>
+            </div>
+            <div>
+              <dt>Frame Rate</dt>
+              <dd>
+                {videoInfo.frameRate
+                  ? `${videoInfo.frameRate.toFixed(2)} fps${videoInfo.variableFrameRate ? " (variable)" : ""}`
+                  : "Unavailable"}
+              </dd>
+            </div>
+            <div>
+              <dt>Bitrate</dt>
+              <dd>{videoInfo.bitRate ? `${Math.round(videoInfo.bitRate / 1000)} kb/s` : "Unavailable"}</dd>
+            </div>
+            <div>
+              <dt>Tracks</dt>
+              <dd>
+                {videoInfo.audioStreams.length} audio / {videoInfo.subtitleStreams.length} subtitle
+                {videoInfo.chapters.length > 0 && `, ${videoInfo.chapters.length} chapters`}
               </dd>
             </div>
           </dl>
         )}
 
+        {videoInfo && videoInfo.audioStreams.length > 1 && (
+          <div className="audio-track-selector">
+            <label htmlFor="audio-track">Audio Track</label>
+            <select
+              id="audio-track"
+              value={audioStreamIndex ?? ""}
+              onChange={(event) => {
+                const next = event.currentTarget.value;
+                setAudioStreamIndex(next === "" ? null : Number(next));
+              }}
+            >
+              <option value="">Automatic (match source language)</option>
+              {videoInfo.audioStreams.map((stream) => (
+                <option key={stream.index} value={stream.index}>
+                  {formatAudioStream(stream)}
+                </option>
+              ))}
+            </select>
+          </div>
+        )}
+
+        {chunkNotice && <p className="warning-text">{chunkNotice}</p>}
+
         <CostEstimate estimate={costEstimate} isLoading={analysisLoading} error={analysisError} />
       </section>
 
@@ -240,23 +535,201 @@ function App() {
         <div className="section-header">
           <h2>5. Process</h2>
         </div>
-        <button type="button" className="primary-btn process-btn" disabled={!canProcess}>
-          Process Video
+        {textSubtitleStreams.length > 0 && (
+          <div className="embedded-subtitles">
+            <p className="info-text">
+              This video already contains text subtitles. Extracting them skips transcription and its cost.
+            </p>
+            <select
+              aria-label="Embedded subtitle track"
+              value={selectedEmbeddedStream ?? ""}
+              onChange={(event) => setEmbeddedStreamIndex(Number(event.currentTarget.value))}
+            >
+              {textSubtitleStreams.map((stream) => (
+                <option key={stream.index} value={stream.index}>
+                  #{stream.index} {stream.title ? `${stream.title} ` : ""}({stream.language ?? "unknown language"},{" "}
+                  {stream.codecName}
+                  {stream.isForced ? ", forced" : ""})
+                </option>
+              ))}
+            </select>
+            <select
+              aria-label="Subtitle format"
+              value={embeddedFormat}
+              onChange={(event) => setEmbeddedFormat(event.currentTarget.value as SubtitleFormat)}
+            >
+              <option value="srt">SRT</option>
+              <option value="ass">ASS</option>
+              <option value="vtt">WebVTT</option>
+            </select>
+            <button
+              type="button"
+              className="secondary-btn"
+              disabled={!canProcess}
+              onClick={() => void handleExtractSubtitles()}
+            >
+              {isExtracting ? "Extracting..." : "Use Embedded Subtitles"}
+            </button>
+            {isExtracting && (
+              <button type="button" className="secondary-btn" onClick={() => void cancelProcessing()}>
+                Cancel
+              </button>
+            )}
+          </div>
+        )}
+        <button
+          type="button"
+          className="primary-btn process-btn"
+          disabled={!canProcess}
+          onClick={() => void handleProcess()}
+        >
+          {isProcessing ? "Processing..." : "Process Video"}
         </button>
-        {!canProcess && (
+        {isProcessing && (
+          <button
+            type="button"
+            className="secondary-btn"
+            onClick={() => {
+              if (window.confirm("Cancel processing? Completed chunks are kept and the job can be resumed later.")) {
+                void cancelProcessing();
+              }
+            }}
+          >
+            Cancel
+          </button>
+        )}
+        {!canProcess && !isProcessing && (
           <p className="info-text">Select and analyze a valid video file to enable processing.</p>
         )}
       </section>
 
-      {isProcessing && (
+      {(isProcessing || isMuxing || isBurningIn) && (
         <section className="card">
           <div className="section-header">
             <h2>Progress</h2>
           </div>
-          <p className="info-text">Processing progress will be displayed here.</p>
+          <progress max={100} value={progress?.percentage ?? 0} />
+          <p className="info-text">{progress?.message ?? "Preparing..."}</p>
+          {retryNotice && (
+            <p className="warning-text">
+              Chunk {retryNotice.chunkNumber}/{retryNotice.totalChunks}: retry {retryNotice.retry} of{" "}
+              {retryNotice.maxRetries} in {Math.ceil(retryNotice.delaySeconds)}s ({retryNotice.error})
+            </p>
+          )}
         </section>
       )}
 
+      {processingResult && (
+        <p className="info-text">
+          Subtitles written to {processingResult.outputPath} ({processingResult.segmentCount} segments).
+          {processingResult.translatedOutputPath && ` Translation written to ${processingResult.translatedOutputPath}.`}
+        </p>
+      )}
+
+      {processingResult && selectedVideo && (
+        <section className="card">
+          <div className="section-header">
+            <h2>Subtitled Video</h2>
+          </div>
+          <p className="info-text">
+            Write a copy of the video with these subtitles as selectable tracks. Video and audio are copied without
+            re-encoding.
+          </p>
+          <ul className="mux-tracks">
+            {muxTracks.map((track) => (
+              <li key={track.path}>
+                <span>{track.path}</span>
+                <select
+                  aria-label="Track language"
+                  value={track.language ?? ""}
+                  onChange={(event) => {
+                    const language = event.currentTarget.value || null;
+                    setMuxTracks((tracks) =>
+                      tracks.map((existing) => (existing.path === track.path ? { ...existing, language } : existing)),
+                    );
+                  }}
+                >
+                  <option value="">Unknown language</option>
+                  {allLanguages.map((language) => (
+                    <option key={language.code} value={language.code}>
+                      {language.name}
+                    </option>
+                  ))}
+                </select>
+                <button
+                  type="button"
+                  className="secondary-btn"
+                  onClick={() => setMuxTracks((tracks) => tracks.filter((existing) => existing.path !== track.path))}
+                >
+                  Remove
+                </button>
+              </li>
+            ))}
+          </ul>
+          <button
+            type="button"
+            className="secondary-btn"
+            disabled={isMuxing}
+            onClick={() => void handleAddSubtitleFiles()}
+          >
+            Add Subtitle Files...
+          </button>
+          <select
+            aria-label="Container"
+            value={muxContainer ?? ""}
+            onChange={(event) => setMuxContainer((event.currentTarget.value || null) as MuxContainer | null)}
+          >
+            <option value="">Same as source</option>
+            <option value="mkv">MKV</option>
+            <option value="mp4">MP4</option>
+          </select>
+          <button
+            type="button"
+            className="primary-btn"
+            disabled={!canProcess || muxTracks.length === 0}
+            onClick={() => void handleMux()}
+          >
+            {isMuxing ? "Writing..." : "Create Subtitled Video"}
+          </button>
+          {isMuxing && (
+            <button type="button" className="secondary-btn" onClick={() => void cancelProcessing()}>
+              Cancel
+            </button>
+          )}
+          {muxedPath && <p className="info-text">Subtitled video written to {muxedPath}.</p>}
+        </section>
+      )}
+
+      {processingResult && selectedVideo && videoInfo && (
+        <BurnInPanel
+          videoPath={selectedVideo.path}
+          durationSeconds={videoInfo.durationSeconds}
+          subtitlePaths={[
+            ...new Set([
+              processingResult.outputPath,
+              ...(processingResult.translatedOutputPath ? [processingResult.translatedOutputPath] : []),
+              ...muxTracks.map((track) => track.path),
+            ]),
+          ]}
+          outputFolder={outputFolderOverride}
+          presets={settings?.stylePresets ?? []}
+          initialStyle={activeStyle}
+          disabled={!canProcess}
+          onBusyChange={(busy) => {
+            setIsBurningIn(busy);
+            if (busy) setProgress(null);
+          }}
+        />
+      )}
+
+      {extractedPath && <p className="info-text">Embedded subtitles written to {extractedPath}.</p>}
+
+      {processingError && <p className="error-text">{processingError}</p>}
+
+      <QueuePanel overrides={jobOverrides} />
+
+      <WatchFoldersPanel overrides={jobOverrides} />
+
       <SettingsModal
         isOpen={isSettingsOpen}
         onClose={() => setIsSettingsOpen(false)}
@@ -265,6 +738,7 @@ function App() {
           setSourceLanguage(nextSettings.sourceLanguage);
           setTargetLanguage(nextSettings.targetLanguage);
           setOutputFolderOverride(nextSettings.defaultOutputFolder);
+          setOutputFormat(nextSettings.defaultOutputFormat);
         }}
       />
     </main>
@@ -0,0 +1,160 @@
+import { useEffect, useState } from "react";
+import { burnInSubtitles, cancelProcessing, renderBurnInPreview } from "../services/tauri-commands";
+import type { StylePreset, SubtitleStyle } from "../types/style";
+import { StyleFields } from "./StyleFields";
+
+interface BurnInPanelProps {
+  videoPath: string;
+  durationSeconds: number;
+  subtitlePaths: string[];
+  outputFolder: string | null;
+  presets: StylePreset[];
+  initialStyle: SubtitleStyle;
+  disabled: boolean;
+  onBusyChange: (busy: boolean) => void;
+}
+
+export function BurnInPanel({
+  videoPath,
+  durationSeconds,
+  subtitlePaths,
+  outputFolder,
+  presets,
+  initialStyle,
+  disabled,
+  onBusyChange,
+}: BurnInPanelProps) {
+  const [subtitlePath, setSubtitlePath] = useState<string>(subtitlePaths[0] ?? "");
+  const [style, setStyle] = useState<SubtitleStyle>(initialStyle);
+  const [previewSeconds, setPreviewSeconds] = useState(Math.floor(durationSeconds / 2));
+  const [previewUrl, setPreviewUrl] = useState<string | null>(null);
+  const [isPreviewing, setIsPreviewing] = useState(false);
+  const [isRendering, setIsRendering] = useState(false);
+  const [renderedPath, setRenderedPath] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!subtitlePaths.includes(subtitlePath)) {
+      setSubtitlePath(subtitlePaths[0] ?? "");
+    }
+  }, [subtitlePaths, subtitlePath]);
+
+  useEffect(() => {
+    return () => {
+      if (previewUrl) URL.revokeObjectURL(previewUrl);
+    };
+  }, [previewUrl]);
+
+  const handlePreview = async () => {
+    if (!subtitlePath) return;
+
+    setIsPreviewing(true);
+    setError(null);
+    try {
+      const bytes = await renderBurnInPreview(videoPath, subtitlePath, style, previewSeconds);
+      setPreviewUrl(URL.createObjectURL(new Blob([bytes], { type: "image/jpeg" })));
+    } catch (previewError) {
+      setError(previewError instanceof Error ? previewError.message : "Failed to render preview.");
+    } finally {
+      setIsPreviewing(false);
+    }
+  };
+
+  const handleRender = async () => {
+    if (!subtitlePath) return;
+
+    setIsRendering(true);
+    onBusyChange(true);
+    setRenderedPath(null);
+    setError(null);
+    try {
+      setRenderedPath(await burnInSubtitles(videoPath, subtitlePath, style, outputFolder));
+    } catch (renderError) {
+      setError(renderError instanceof Error ? renderError.message : "Failed to render subtitles into the video.");
+    } finally {
+      setIsRendering(false);
+      onBusyChange(false);
+    }
+  };
+
+  return (
+    <section className="card">
+      <div className="section-header">
+        <h2>Burned-in Subtitles</h2>
+      </div>
+      <p className="info-text">
+        Draw subtitles onto the picture for players and sites without subtitle support. The video is re-encoded.
+      </p>
+      <div className="row-inline">
+        <label>
+          Subtitle file
+          <select value={subtitlePath} onChange={(event) => setSubtitlePath(event.currentTarget.value)}>
+            {subtitlePaths.map((path) => (
+              <option key={path} value={path}>
+                {path}
+              </option>
+            ))}
+          </select>
+        </label>
+        {presets.length > 0 && (
+          <label>
+            Style preset
+            <select
+              value=""
+              onChange={(event) => {
+                const preset = presets.find((candidate) => candidate.name === event.currentTarget.value);
+                if (preset) setStyle(preset.style);
+              }}
+            >
+              <option value="">Apply a preset...</option>
+              {presets.map((preset) => (
+                <option key={preset.name} value={preset.name}>
+                  {preset.name}
+                </option>
+              ))}
+            </select>
+          </label>
+        )}
+      </div>
+      <StyleFields style={style} onChange={setStyle} />
+      <div className="row-inline">
+        <label>
+          Preview at (seconds)
+          <input
+            type="number"
+            min={0}
+            max={Math.floor(durationSeconds)}
+            value={previewSeconds}
+            onChange={(event) => setPreviewSeconds(Number(event.currentTarget.value))}
+          />
+        </label>
+        <button
+          type="button"
+          className="secondary-btn"
+          disabled={!subtitlePath || isPreviewing}
+          onClick={() => void handlePreview()}
+        >
+          {isPreviewing ? "Rendering..." : "Preview Frame"}
+        </button>
+      </div>
+      {previewUrl && <img className="burn-in-preview" src={previewUrl} alt="Subtitle preview frame" />}
+      <div className="row-inline">
+        <button
+          type="button"
+          className="primary-btn"
+          disabled={disabled || !subtitlePath}
+          onClick={() => void handleRender()}
+        >
+          {isRendering ? "Rendering..." : "Render Video"}
+        </button>
+        {isRendering && (
+          <button type="button" className="secondary-btn" onClick={() => void cancelProcessing()}>
+            Cancel
+          </button>
+        )}
+      </div>
+      {renderedPath && <p className="info-text">Video with burned-in subtitles written to {renderedPath}.</p>}
+      {error && <p className="error-text">{error}</p>}
+    </section>
+  );
+}
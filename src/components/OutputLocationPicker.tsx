@@ -1,13 +1,22 @@
 import { useState } from "react";
 import { selectOutputFolder } from "../services/tauri-commands";
+import type { SubtitleFormat } from "../types/settings";
 
 interface OutputLocationPickerProps {
   outputFolder: string | null;
   fallbackLabel: string;
   onChange: (folder: string | null) => void;
+  outputFormat: SubtitleFormat;
+  onFormatChange: (format: SubtitleFormat) => void;
 }
 
-export function OutputLocationPicker({ outputFolder, fallbackLabel, onChange }: OutputLocationPickerProps) {
+export function OutputLocationPicker({
+  outputFolder,
+  fallbackLabel,
+  onChange,
+  outputFormat,
+  onFormatChange,
+}: OutputLocationPickerProps) {
   const [error, setError] = useState<string | null>(null);
   const [isPicking, setIsPicking] = useState(false);
 
@@ -41,6 +50,15 @@ export function OutputLocationPicker({ outputFolder, fallbackLabel, onChange }:
         <button type="button" className="secondary-btn" onClick={() => onChange(null)}>
           Reset to Default
         </button>
+        <select
+          aria-label="Subtitle format"
+          value={outputFormat}
+          onChange={(event) => onFormatChange(event.currentTarget.value as SubtitleFormat)}
+        >
+          <option value="srt">SRT</option>
+          <option value="ass">ASS</option>
+          <option value="vtt">WebVTT</option>
+        </select>
       </div>
       {error && <p className="error-text">{error}</p>}
     </section>
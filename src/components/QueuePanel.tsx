@@ -0,0 +1,141 @@
+import { useEffect, useState } from "react";
+import {
+  addToQueue,
+  getQueue,
+  onQueueProgress,
+  onQueueUpdated,
+  pauseQueueJob,
+  removeFromQueue,
+  reorderQueue,
+  resumeQueueJob,
+  selectVideoFiles,
+  setQueuePaused,
+} from "../services/tauri-commands";
+import type { JobOverrides, JobQueue, QueueJob, QueueJobProgress } from "../types/queue";
+
+interface QueuePanelProps {
+  overrides: JobOverrides;
+}
+
+const STATUS_LABELS: Record<QueueJob["status"], string> = {
+  queued: "Queued",
+  running: "Running",
+  paused: "Paused",
+  completed: "Done",
+  failed: "Failed",
+};
+
+function fileName(path: string): string {
+  const normalized = path.replace(/\\/g, "/");
+  return normalized.slice(normalized.lastIndexOf("/") + 1);
+}
+
+export function QueuePanel({ overrides }: QueuePanelProps) {
+  const [queue, setQueue] = useState<JobQueue>({ paused: false, jobs: [] });
+  const [progress, setProgress] = useState<Record<string, QueueJobProgress>>({});
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    getQueue()
+      .then(setQueue)
+      .catch((loadError) => console.error("Failed to load job queue", loadError));
+
+    const subscriptions = [
+      onQueueUpdated(setQueue),
+      onQueueProgress((next) => setProgress((current) => ({ ...current, [next.jobId]: next }))),
+    ];
+
+    return () => {
+      subscriptions.forEach((subscription) => void subscription.then((unlisten) => unlisten()));
+    };
+  }, []);
+
+  const run = async (action: () => Promise<JobQueue>) => {
+    setError(null);
+    try {
+      setQueue(await action());
+    } catch (actionError) {
+      setError(actionError instanceof Error ? actionError.message : "Queue update failed.");
+    }
+  };
+
+  const handleAddVideos = async () => {
+    const paths = await selectVideoFiles().catch((pickError: unknown) => {
+      setError(pickError instanceof Error ? pickError.message : "Failed to select video files.");
+      return [];
+    });
+    if (paths.length > 0) {
+      await run(() => addToQueue(paths, overrides));
+    }
+  };
+
+  const moveJob = (index: number, offset: number) => {
+    const ids = queue.jobs.map((job) => job.id);
+    const [moved] = ids.splice(index, 1);
+    ids.splice(index + offset, 0, moved);
+    void run(() => reorderQueue(ids));
+  };
+
+  return (
+    <section className="card">
+      <div className="section-header">
+        <h2>Batch Queue</h2>
+      </div>
+      <div className="row-inline">
+        <button type="button" className="primary-btn" onClick={() => void handleAddVideos()}>
+          Add Videos
+        </button>
+        <button type="button" className="secondary-btn" onClick={() => void run(() => setQueuePaused(!queue.paused))}>
+          {queue.paused ? "Resume Queue" : "Pause Queue"}
+        </button>
+      </div>
+      <p className="info-text">New jobs use the languages and output location chosen above.</p>
+
+      {queue.jobs.map((job, index) => {
+        const jobProgress = progress[job.id];
+        return (
+          <div key={job.id} className="job-row">
+            <div className="info-text">
+              <strong>{fileName(job.videoPath)}</strong> - {STATUS_LABELS[job.status]}
+              {job.status === "running" && jobProgress && ` (${jobProgress.message})`}
+              {job.status === "completed" && job.outputPath && ` - ${job.outputPath}`}
+              {job.error && <span className="error-text"> {job.error}</span>}
+              {job.status === "running" && <progress max={100} value={jobProgress?.percentage ?? 0} />}
+            </div>
+            <button
+              type="button"
+              className="secondary-btn"
+              disabled={index === 0}
+              onClick={() => moveJob(index, -1)}
+            >
+              Up
+            </button>
+            <button
+              type="button"
+              className="secondary-btn"
+              disabled={index === queue.jobs.length - 1}
+              onClick={() => moveJob(index, 1)}
+            >
+              Down
+            </button>
+            {(job.status === "queued" || job.status === "running") && (
+              <button type="button" className="secondary-btn" onClick={() => void run(() => pauseQueueJob(job.id))}>
+                Pause
+              </button>
+            )}
+            {(job.status === "paused" || job.status === "failed") && (
+              <button type="button" className="secondary-btn" onClick={() => void run(() => resumeQueueJob(job.id))}>
+                {job.status === "failed" ? "Retry" : "Resume"}
+              </button>
+            )}
+            <button type="button" className="secondary-btn" onClick={() => void run(() => removeFromQueue(job.id))}>
+              Remove
+            </button>
+          </div>
+        );
+      })}
+
+      {error && <p className="error-text">{error}</p>}
+    </section>
+  );
+}
@@ -1,7 +1,23 @@
 import { useEffect, useMemo, useState } from "react";
-import { getSettings, getSupportedLanguages, saveSettings, selectOutputFolder } from "../services/tauri-commands";
+import {
+  getProviderCapabilities,
+  getSettings,
+  getSupportedLanguages,
+  saveSettings,
+  selectModelFile,
+  selectOutputFolder,
+} from "../services/tauri-commands";
 import { WHISPER_LANGUAGES, type Language } from "../types/languages";
-import { DEFAULT_SETTINGS, type AppSettings } from "../types/settings";
+import {
+  DEFAULT_SETTINGS,
+  type AppSettings,
+  type ChunkingMode,
+  type ProviderCapabilities,
+  type ProviderKind,
+  type SubtitleFormat,
+  type TranslationMode,
+} from "../types/settings";
+import { StylePresetsEditor } from "./StylePresetsEditor";
 
 interface SettingsModalProps {
   isOpen: boolean;
@@ -12,7 +28,19 @@ interface SettingsModalProps {
 interface FormErrors {
   chunkDurationMinutes?: string;
   maxRetries?: string;
+  silenceWindowSeconds?: string;
+  chunkOverlapSeconds?: string;
+  maxConcurrentJobs?: string;
   sourceLanguage?: string;
+  apiBaseUrl?: string;
+  azureEndpoint?: string;
+  azureDeployment?: string;
+  compatibleModel?: string;
+  compatiblePricePerMinuteUsd?: string;
+  whisperCppModelPath?: string;
+  translationApiBaseUrl?: string;
+  translationModel?: string;
+  stylePresets?: string;
 }
 
 export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps) {
@@ -22,6 +50,7 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
   const [isLoading, setIsLoading] = useState(false);
   const [isSaving, setIsSaving] = useState(false);
   const [apiKeyTestMessage, setApiKeyTestMessage] = useState<string | null>(null);
+  const [capabilities, setCapabilities] = useState<ProviderCapabilities | null>(null);
 
   useEffect(() => {
     if (!isOpen) return;
@@ -66,6 +95,23 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
     };
   }, [isOpen]);
 
+  useEffect(() => {
+    if (!isOpen) return;
+
+    let cancelled = false;
+    getProviderCapabilities(settings)
+      .then((next) => {
+        if (!cancelled) setCapabilities(next);
+      })
+      .catch(() => {
+        if (!cancelled) setCapabilities(null);
+      });
+
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, settings]);
+
   const availableLanguages = useMemo(() => {
     if (settings.preferredLanguages.length === 0) {
       return allLanguages;
@@ -82,6 +128,18 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
       nextErrors.chunkDurationMinutes = "Chunk duration must be between 5 and 60.";
     }
 
+    if (settings.silenceWindowSeconds < 5 || settings.silenceWindowSeconds > 120) {
+      nextErrors.silenceWindowSeconds = "Silence search window must be between 5 and 120 seconds.";
+    }
+
+    if (settings.chunkOverlapSeconds < 1 || settings.chunkOverlapSeconds > 60) {
+      nextErrors.chunkOverlapSeconds = "Chunk overlap must be between 1 and 60 seconds.";
+    }
+
+    if (settings.maxConcurrentJobs < 1 || settings.maxConcurrentJobs > 4) {
+      nextErrors.maxConcurrentJobs = "Concurrent jobs must be between 1 and 4.";
+    }
+
     if (settings.maxRetries < 1 || settings.maxRetries > 10) {
       nextErrors.maxRetries = "Max retries must be between 1 and 10.";
     }
@@ -90,6 +148,49 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
       nextErrors.sourceLanguage = "Source language is required.";
     }
 
+    if (!/^https?:\/\//.test(settings.apiBaseUrl.trim())) {
+      nextErrors.apiBaseUrl = "API base URL must start with http:// or https://.";
+    }
+
+    if (settings.provider === "azure") {
+      if (!/^https?:\/\//.test(settings.azureEndpoint.trim())) {
+        nextErrors.azureEndpoint = "Azure endpoint must start with http:// or https://.";
+      }
+      if (!settings.azureDeployment.trim()) {
+        nextErrors.azureDeployment = "Azure deployment name is required.";
+      }
+    }
+
+    if (settings.provider === "whispercpp" && !settings.whisperCppModelPath?.trim()) {
+      nextErrors.whisperCppModelPath = "Select a whisper.cpp model file.";
+    }
+
+    if (!/^https?:\/\//.test(settings.translationApiBaseUrl.trim())) {
+      nextErrors.translationApiBaseUrl = "Translation API base URL must start with http:// or https://.";
+    }
+
+    if (!settings.translationModel.trim()) {
+      nextErrors.translationModel = "Translation model is required.";
+    }
+
+    const presetNames = settings.stylePresets.map((preset) => preset.name.trim());
+    if (presetNames.some((name) => !name)) {
+      nextErrors.stylePresets = "Style presets need a name.";
+    } else if (new Set(presetNames).size !== presetNames.length) {
+      nextErrors.stylePresets = "Style preset names must be unique.";
+    } else if (settings.stylePresets.some((preset) => preset.speakerStyles.some((style) => !style.speaker.trim()))) {
+      nextErrors.stylePresets = "Speaker styles need a speaker name.";
+    }
+
+    if (settings.provider === "compatible") {
+      if (!settings.compatibleModel.trim()) {
+        nextErrors.compatibleModel = "Model name is required for OpenAI-compatible servers.";
+      }
+      if (!(settings.compatiblePricePerMinuteUsd >= 0)) {
+        nextErrors.compatiblePricePerMinuteUsd = "Price per minute must be zero or a positive amount.";
+      }
+    }
+
     setErrors(nextErrors);
     return Object.keys(nextErrors).length === 0;
   };
@@ -101,6 +202,13 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
     }
   };
 
+  const handleBrowseModelFile = async () => {
+    const modelPath = await selectModelFile();
+    if (modelPath) {
+      setSettings((current) => ({ ...current, whisperCppModelPath: modelPath }));
+    }
+  };
+
   const handleSave = async () => {
     if (!validate()) return;
 
@@ -147,15 +255,126 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
         ) : (
           <div className="settings-grid">
             <label>
-              API Key
-              <input
-                type="password"
-                value={settings.apiKey}
-                onChange={(e) => setSettings((s) => ({ ...s, apiKey: e.currentTarget.value }))}
-                placeholder="sk-..."
-              />
+              Transcription provider
+              <select
+                value={settings.provider}
+                onChange={(e) => setSettings((s) => ({ ...s, provider: e.currentTarget.value as ProviderKind }))}
+              >
+                <option value="openai">OpenAI</option>
+                <option value="azure">Azure OpenAI</option>
+                <option value="compatible">OpenAI-compatible server</option>
+                <option value="whispercpp">Local whisper.cpp (offline)</option>
+              </select>
+              {capabilities && (
+                <span className="info-text">
+                  ${capabilities.pricePerMinuteUsd.toFixed(4)}/min
+                  {Number.isSafeInteger(capabilities.maxUploadBytes)
+                    ? `, ${Math.round(capabilities.maxUploadBytes / 1048576)} MB upload limit`
+                    : ", no upload limit"}
+                  {capabilities.translation ? ", translation" : ""}
+                  {capabilities.wordTimestamps ? ", word timestamps" : ""}
+                </span>
+              )}
             </label>
 
+            {settings.provider !== "whispercpp" && (
+              <label>
+                API Key{settings.provider === "compatible" ? " (optional)" : ""}
+                <input
+                  type="password"
+                  value={settings.apiKey}
+                  onChange={(e) => setSettings((s) => ({ ...s, apiKey: e.currentTarget.value }))}
+                  placeholder={settings.provider === "openai" ? "sk-..." : ""}
+                />
+              </label>
+            )}
+
+            {settings.provider === "whispercpp" ? (
+              <div>
+                <label>Model file</label>
+                <div className="row-inline">
+                  <input type="text" value={settings.whisperCppModelPath ?? "No model selected"} readOnly />
+                  <button type="button" className="secondary-btn" onClick={() => void handleBrowseModelFile()}>
+                    Browse
+                  </button>
+                </div>
+                {errors.whisperCppModelPath && <span className="error-text">{errors.whisperCppModelPath}</span>}
+              </div>
+            ) : settings.provider === "azure" ? (
+              <>
+                <label>
+                  Azure endpoint
+                  <input
+                    type="url"
+                    value={settings.azureEndpoint}
+                    onChange={(e) => setSettings((s) => ({ ...s, azureEndpoint: e.currentTarget.value }))}
+                    placeholder="https://your-resource.openai.azure.com"
+                  />
+                  {errors.azureEndpoint && <span className="error-text">{errors.azureEndpoint}</span>}
+                </label>
+
+                <label>
+                  Deployment name
+                  <input
+                    type="text"
+                    value={settings.azureDeployment}
+                    onChange={(e) => setSettings((s) => ({ ...s, azureDeployment: e.currentTarget.value }))}
+                  />
+                  {errors.azureDeployment && <span className="error-text">{errors.azureDeployment}</span>}
+                </label>
+
+                <label>
+                  API version
+                  <input
+                    type="text"
+                    value={settings.azureApiVersion}
+                    onChange={(e) => setSettings((s) => ({ ...s, azureApiVersion: e.currentTarget.value }))}
+                  />
+                </label>
+              </>
+            ) : (
+              <label>
+                API base URL
+                <input
+                  type="url"
+                  value={settings.apiBaseUrl}
+                  onChange={(e) => setSettings((s) => ({ ...s, apiBaseUrl: e.currentTarget.value }))}
+                  placeholder="https://api.openai.com"
+                />
+                {errors.apiBaseUrl && <span className="error-text">{errors.apiBaseUrl}</span>}
+              </label>
+            )}
+
+            {settings.provider === "compatible" && (
+              <>
+                <label>
+                  Model
+                  <input
+                    type="text"
+                    value={settings.compatibleModel}
+                    onChange={(e) => setSettings((s) => ({ ...s, compatibleModel: e.currentTarget.value }))}
+                  />
+                  {errors.compatibleModel && <span className="error-text">{errors.compatibleModel}</span>}
+                </label>
+
+                <label>
+                  Price per minute (USD)
+                  <input
+                    type="number"
+                    min={0}
+                    step={0.001}
+                    value={settings.compatiblePricePerMinuteUsd}
+                    onChange={(e) =>
+                      setSettings((s) => ({ ...s, compatiblePricePerMinuteUsd: Number(e.currentTarget.value) }))
+                    }
+                  />
+                  {errors.compatiblePricePerMinuteUsd && (
+                    <span className="error-text">{errors.compatiblePricePerMinuteUsd}</span>
+                  )}
+                </label>
+              </>
+            )}
+
             <div>
               <label>Default output folder</label>
               <div className="row-inline">
@@ -177,6 +396,32 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
               </div>
             </div>
 
+            <label>
+              Subtitle format
+              <select
+                value={settings.defaultOutputFormat}
+                onChange={(e) =>
+                  setSettings((s) => ({ ...s, defaultOutputFormat: e.currentTarget.value as SubtitleFormat }))
+                }
+              >
+                <option value="srt">SRT</option>
+                <option value="ass">ASS (karaoke word highlighting when available)</option>
+                <option value="vtt">WebVTT</option>
+              </select>
+            </label>
+
+            <div>
+              <StylePresetsEditor
+                presets={settings.stylePresets}
+                activePreset={settings.activeStylePreset}
+                languages={allLanguages}
+                onChange={(stylePresets, activeStylePreset) =>
+                  setSettings((s) => ({ ...s, stylePresets, activeStylePreset }))
+                }
+              />
+              {errors.stylePresets && <span className="error-text">{errors.stylePresets}</span>}
+            </div>
+
             <label>
               Chunk duration (minutes)
               <input
@@ -191,6 +436,64 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
               {errors.chunkDurationMinutes && <span className="error-text">{errors.chunkDurationMinutes}</span>}
             </label>
 
+            <label>
+              Chunk boundaries
+              <select
+                value={settings.chunkingMode}
+                onChange={(e) =>
+                  setSettings((s) => ({ ...s, chunkingMode: e.currentTarget.value as ChunkingMode }))
+                }
+              >
+                <option value="fixed">Fixed intervals</option>
+                <option value="silence">Snap to nearest silence</option>
+                <option value="overlap">Overlap neighbouring chunks</option>
+              </select>
+            </label>
+
+            {settings.chunkingMode === "silence" && (
+              <label>
+                Silence search window (seconds)
+                <input
+                  type="number"
+                  min={5}
+                  max={120}
+                  value={settings.silenceWindowSeconds}
+                  onChange={(e) =>
+                    setSettings((s) => ({ ...s, silenceWindowSeconds: Number(e.currentTarget.value) }))
+                  }
+                />
+                {errors.silenceWindowSeconds && <span className="error-text">{errors.silenceWindowSeconds}</span>}
+              </label>
+            )}
+
+            {settings.chunkingMode === "overlap" && (
+              <label>
+                Chunk overlap (seconds)
+                <input
+                  type="number"
+                  min={1}
+                  max={60}
+                  value={settings.chunkOverlapSeconds}
+                  onChange={(e) =>
+                    setSettings((s) => ({ ...s, chunkOverlapSeconds: Number(e.currentTarget.value) }))
+                  }
+                />
+                {errors.chunkOverlapSeconds && <span className="error-text">{errors.chunkOverlapSeconds}</span>}
+              </label>
+            )}
+
+            <label>
+              Concurrent queue jobs
+              <input
+                type="number"
+                min={1}
+                max={4}
+                value={settings.maxConcurrentJobs}
+                onChange={(e) => setSettings((s) => ({ ...s, maxConcurrentJobs: Number(e.currentTarget.value) }))}
+              />
+              {errors.maxConcurrentJobs && <span className="error-text">{errors.maxConcurrentJobs}</span>}
+            </label>
+
             <label>
               Max retries
               <input
@@ -233,6 +536,54 @@ export function SettingsModal({ isOpen, onClose, onSaved }: SettingsModalProps)
                   </option>
                 ))}
               </select>
+              <span className="info-text">Translations are written as a separate name.language.srt file.</span>
+            </label>
+
+            <label>
+              Translation method
+              <select
+                value={settings.translationMode}
+                onChange={(e) =>
+                  setSettings((s) => ({ ...s, translationMode: e.currentTarget.value as TranslationMode }))
+                }
+              >
+                <option value="chat">Translate the transcript (any language)</option>
+                <option value="audio">Provider audio translation (English only)</option>
+              </select>
+              <span className="info-text">
+                Audio translation replaces the transcript with English. Other targets still use the chat model.
+              </span>
+            </label>
+
+            <label>
+              Translation API base URL
+              <input
+                type="url"
+                value={settings.translationApiBaseUrl}
+                onChange={(e) => setSettings((s) => ({ ...s, translationApiBaseUrl: e.currentTarget.value }))}
+                placeholder="https://api.openai.com"
+              />
+              {errors.translationApiBaseUrl && <span className="error-text">{errors.translationApiBaseUrl}</span>}
+            </label>
+
+            <label>
+              Translation model
+              <input
+                type="text"
+                value={settings.translationModel}
+                onChange={(e) => setSettings((s) => ({ ...s, translationModel: e.currentTarget.value }))}
+              />
+              {errors.translationModel && <span className="error-text">{errors.translationModel}</span>}
+            </label>
+
+            <label>
+              Translation API key (optional)
+              <input
+                type="password"
+                value={settings.translationApiKey}
+                onChange={(e) => setSettings((s) => ({ ...s, translationApiKey: e.currentTarget.value }))}
+                placeholder="Same as the transcription API key"
+              />
             </label>
 
             <div>
@@ -0,0 +1,120 @@
+import type { HorizontalAlignment, SubtitlePosition, SubtitleStyle } from "../types/style";
+
+interface StyleFieldsProps {
+  style: SubtitleStyle;
+  onChange: (style: SubtitleStyle) => void;
+}
+
+export function StyleFields({ style, onChange }: StyleFieldsProps) {
+  const update = (patch: Partial<SubtitleStyle>) => onChange({ ...style, ...patch });
+
+  return (
+    <div className="style-fields">
+      <label>
+        Font
+        <input value={style.fontName} onChange={(event) => update({ fontName: event.currentTarget.value })} />
+      </label>
+      <label>
+        Size
+        <input
+          type="number"
+          min={1}
+          max={200}
+          value={style.fontSize}
+          onChange={(event) => update({ fontSize: Number(event.currentTarget.value) })}
+        />
+      </label>
+      <label>
+        <input
+          type="checkbox"
+          checked={style.bold}
+          onChange={(event) => update({ bold: event.currentTarget.checked })}
+        />
+        Bold
+      </label>
+      <label>
+        <input
+          type="checkbox"
+          checked={style.italic}
+          onChange={(event) => update({ italic: event.currentTarget.checked })}
+        />
+        Italic
+      </label>
+      <label>
+        Text color
+        <input
+          type="color"
+          value={style.primaryColor}
+          onChange={(event) => update({ primaryColor: event.currentTarget.value })}
+        />
+      </label>
+      <label>
+        Karaoke highlight
+        <input
+          type="color"
+          value={style.highlightColor}
+          onChange={(event) => update({ highlightColor: event.currentTarget.value })}
+        />
+      </label>
+      <label>
+        Outline color
+        <input
+          type="color"
+          value={style.outlineColor}
+          onChange={(event) => update({ outlineColor: event.currentTarget.value })}
+        />
+      </label>
+      <label>
+        Outline width
+        <input
+          type="number"
+          min={0}
+          max={10}
+          step={0.5}
+          value={style.outlineWidth}
+          onChange={(event) => update({ outlineWidth: Number(event.currentTarget.value) })}
+        />
+      </label>
+      <label>
+        Position
+        <select
+          value={style.position}
+          onChange={(event) => update({ position: event.currentTarget.value as SubtitlePosition })}
+        >
+          <option value="bottom">Bottom</option>
+          <option value="middle">Middle</option>
+          <option value="top">Top</option>
+        </select>
+      </label>
+      <label>
+        Alignment
+        <select
+          value={style.alignment}
+          onChange={(event) => update({ alignment: event.currentTarget.value as HorizontalAlignment })}
+        >
+          <option value="left">Left</option>
+          <option value="center">Center</option>
+          <option value="right">Right</option>
+        </select>
+      </label>
+      <label>
+        Vertical margin
+        <input
+          type="number"
+          min={0}
+          value={style.marginVertical}
+          onChange={(event) => update({ marginVertical: Number(event.currentTarget.value) })}
+        />
+      </label>
+      <label>
+        Side margins
+        <input
+          type="number"
+          min={0}
+          value={style.marginHorizontal}
+          onChange={(event) => update({ marginHorizontal: Number(event.currentTarget.value) })}
+        />
+      </label>
+    </div>
+  );
+}
@@ -0,0 +1,191 @@
+import type { Language } from "../types/languages";
+import { DEFAULT_SUBTITLE_STYLE, type StylePreset } from "../types/style";
+import { StyleFields } from "./StyleFields";
+
+interface StylePresetsEditorProps {
+  presets: StylePreset[];
+  activePreset: string | null;
+  languages: Language[];
+  onChange: (presets: StylePreset[], activePreset: string | null) => void;
+}
+
+function nextPresetName(presets: StylePreset[]): string {
+  let number = presets.length + 1;
+  while (presets.some((preset) => preset.name === `Preset ${number}`)) {
+    number += 1;
+  }
+  return `Preset ${number}`;
+}
+
+export function StylePresetsEditor({ presets, activePreset, languages, onChange }: StylePresetsEditorProps) {
+  const selected = presets.find((preset) => preset.name === activePreset) ?? null;
+
+  const updateSelected = (patch: Partial<StylePreset>) => {
+    if (!selected) return;
+    const updated = { ...selected, ...patch };
+    onChange(presets.map((preset) => (preset === selected ? updated : preset)), updated.name);
+  };
+
+  const handleNewPreset = () => {
+    const preset: StylePreset = {
+      name: nextPresetName(presets),
+      style: selected?.style ?? DEFAULT_SUBTITLE_STYLE,
+      languageStyles: [],
+      speakerStyles: [],
+    };
+    onChange([...presets, preset], preset.name);
+  };
+
+  return (
+    <div className="style-presets">
+      <label>
+        ASS style preset
+        <select value={activePreset ?? ""} onChange={(e) => onChange(presets, e.currentTarget.value || null)}>
+          <option value="">Built-in default</option>
+          {presets.map((preset) => (
+            <option key={preset.name} value={preset.name}>
+              {preset.name}
+            </option>
+          ))}
+        </select>
+      </label>
+      <div className="row-inline">
+        <button type="button" className="secondary-btn" onClick={handleNewPreset}>
+          New Preset
+        </button>
+        {selected && (
+          <button
+            type="button"
+            className="secondary-btn"
+            onClick={() => onChange(presets.filter((preset) => preset !== selected), null)}
+          >
+            Delete Preset
+          </button>
+        )}
+      </div>
+
+      {selected && (
+        <>
+          <label>
+            Preset name
+            <input value={selected.name} onChange={(e) => updateSelected({ name: e.currentTarget.value })} />
+          </label>
+          <StyleFields style={selected.style} onChange={(style) => updateSelected({ style })} />
+
+          <h3>Language styles</h3>
+          {selected.languageStyles.map((languageStyle, index) => (
+            <div key={index} className="style-override">
+              <div className="row-inline">
+                <select
+                  aria-label="Language"
+                  value={languageStyle.language}
+                  onChange={(e) => {
+                    const language = e.currentTarget.value;
+                    updateSelected({
+                      languageStyles: selected.languageStyles.map((existing, position) =>
+                        position === index ? { ...existing, language } : existing,
+                      ),
+                    });
+                  }}
+                >
+                  {languages.map((language) => (
+                    <option key={language.code} value={language.code}>
+                      {language.name}
+                    </option>
+                  ))}
+                </select>
+                <button
+                  type="button"
+                  className="secondary-btn"
+                  onClick={() =>
+                    updateSelected({
+                      languageStyles: selected.languageStyles.filter((_, position) => position !== index),
+                    })
+                  }
+                >
+                  Remove
+                </button>
+              </div>
+              <StyleFields
+                style={languageStyle.style}
+                onChange={(style) =>
+                  updateSelected({
+                    languageStyles: selected.languageStyles.map((existing, position) =>
+                      position === index ? { ...existing, style } : existing,
+                    ),
+                  })
+                }
+              />
+            </div>
+          ))}
+          <button
+            type="button"
+            className="secondary-btn"
+            onClick={() =>
+              updateSelected({
+                languageStyles: [
+                  ...selected.languageStyles,
+                  { language: languages[0]?.code ?? "en", style: selected.style },
+                ],
+              })
+            }
+          >
+            Add Language Style
+          </button>
+
+          <h3>Speaker styles</h3>
+          <span className="info-text">Cues starting with the speaker&apos;s name and a colon use that style.</span>
+          {selected.speakerStyles.map((speakerStyle, index) => (
+            <div key={index} className="style-override">
+              <div className="row-inline">
+                <input
+                  aria-label="Speaker name"
+                  placeholder="Speaker name"
+                  value={speakerStyle.speaker}
+                  onChange={(e) => {
+                    const speaker = e.currentTarget.value;
+                    updateSelected({
+                      speakerStyles: selected.speakerStyles.map((existing, position) =>
+                        position === index ? { ...existing, speaker } : existing,
+                      ),
+                    });
+                  }}
+                />
+                <button
+                  type="button"
+                  className="secondary-btn"
+                  onClick={() =>
+                    updateSelected({
+                      speakerStyles: selected.speakerStyles.filter((_, position) => position !== index),
+                    })
+                  }
+                >
+                  Remove
+                </button>
+              </div>
+              <StyleFields
+                style={speakerStyle.style}
+                onChange={(style) =>
+                  updateSelected({
+                    speakerStyles: selected.speakerStyles.map((existing, position) =>
+                      position === index ? { ...existing, style } : existing,
+                    ),
+                  })
+                }
+              />
+            </div>
+          ))}
+          <button
+            type="button"
+            className="secondary-btn"
+            onClick={() =>
+              updateSelected({ speakerStyles: [...selected.speakerStyles, { speaker: "", style: selected.style }] })
+            }
+          >
+            Add Speaker Style
+          </button>
+        </>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,116 @@
+import { useEffect, useState } from "react";
+import {
+  addWatchFolder,
+  listWatchFolders,
+  onWatchFoldersUpdated,
+  removeWatchFolder,
+  selectOutputFolder,
+  selectWatchFolder,
+  updateWatchFolder,
+} from "../services/tauri-commands";
+import type { JobOverrides } from "../types/queue";
+import type { WatchFolder } from "../types/watch";
+
+interface WatchFoldersPanelProps {
+  overrides: JobOverrides;
+}
+
+export function WatchFoldersPanel({ overrides }: WatchFoldersPanelProps) {
+  const [folders, setFolders] = useState<WatchFolder[]>([]);
+  const [archiveFolder, setArchiveFolder] = useState<string | null>(null);
+  const [includeExisting, setIncludeExisting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    listWatchFolders()
+      .then(setFolders)
+      .catch((loadError) => console.error("Failed to load watch folders", loadError));
+
+    const subscription = onWatchFoldersUpdated(setFolders);
+    return () => {
+      void subscription.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  const run = async (action: () => Promise<WatchFolder[]>) => {
+    setError(null);
+    try {
+      setFolders(await action());
+    } catch (actionError) {
+      setError(actionError instanceof Error ? actionError.message : "Watch folder update failed.");
+    }
+  };
+
+  const handleAddFolder = async () => {
+    const path = await selectWatchFolder().catch(() => null);
+    if (path) {
+      await run(() => addWatchFolder(path, overrides, archiveFolder, includeExisting));
+    }
+  };
+
+  const handlePickArchive = async () => {
+    const folder = await selectOutputFolder().catch(() => null);
+    if (folder) {
+      setArchiveFolder(folder);
+    }
+  };
+
+  return (
+    <section className="card">
+      <div className="section-header">
+        <h2>Watch Folders</h2>
+      </div>
+      <p className="info-text">
+        New videos in a watched folder are queued once they stop growing, using the languages and output location
+        chosen above. Videos that already have a transcript are skipped.
+      </p>
+      <div className="row-inline">
+        <button type="button" className="secondary-btn" onClick={() => void handlePickArchive()}>
+          {archiveFolder ? `Archive to ${archiveFolder}` : "Archive processed files..."}
+        </button>
+        {archiveFolder && (
+          <button type="button" className="secondary-btn" onClick={() => setArchiveFolder(null)}>
+            Don&apos;t archive
+          </button>
+        )}
+        <label>
+          <input
+            type="checkbox"
+            checked={includeExisting}
+            onChange={(e) => setIncludeExisting(e.currentTarget.checked)}
+          />
+          Also process videos already in the folder
+        </label>
+        <button type="button" className="primary-btn" onClick={() => void handleAddFolder()}>
+          Watch Folder
+        </button>
+      </div>
+
+      {folders.map((folder) => (
+        <div key={folder.id} className="job-row">
+          <p className="info-text">
+            <strong>{folder.path}</strong>
+            {folder.archiveFolder ? ` - archives to ${folder.archiveFolder}` : ""}
+            {folder.overrides.targetLanguage ? ` - translates to ${folder.overrides.targetLanguage}` : ""}
+          </p>
+          <label>
+            <input
+              type="checkbox"
+              checked={folder.enabled}
+              onChange={(e) => {
+                const enabled = e.currentTarget.checked;
+                void run(() => updateWatchFolder({ ...folder, enabled }));
+              }}
+            />
+            Enabled
+          </label>
+          <button type="button" className="secondary-btn" onClick={() => void run(() => removeWatchFolder(folder.id))}>
+            Remove
+          </button>
+        </div>
+      ))}
+
+      {error && <p className="error-text">{error}</p>}
+    </section>
+  );
+}
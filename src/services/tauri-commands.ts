@@ -1,7 +1,25 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import type { Language } from "../types/languages";
-import type { ChunkInfo, CostEstimate, VideoInfo } from "../types/processing";
-import type { AppSettings } from "../types/settings";
+import type {
+  ChunkPlan,
+  CostEstimate,
+  JobSummary,
+  MuxContainer,
+  MuxTrack,
+  ProcessingConfig,
+  ProcessingProgress,
+  ProcessingResult,
+  ProcessingRetry,
+  VideoInfo,
+} from "../types/processing";
+import { PROCESSING_PROGRESS_EVENT, PROCESSING_RETRY_EVENT } from "../types/processing";
+import type { JobOverrides, JobQueue, QueueJobProgress, QueueJobRetry } from "../types/queue";
+import { QUEUE_PROGRESS_EVENT, QUEUE_RETRY_EVENT, QUEUE_UPDATED_EVENT } from "../types/queue";
+import type { WatchFolder } from "../types/watch";
+import { WATCH_FOLDERS_UPDATED_EVENT } from "../types/watch";
+import type { AppSettings, ProviderCapabilities, SubtitleFormat } from "../types/settings";
+import type { SubtitleStyle } from "../types/style";
 import type { VideoFileInfo } from "../types/video";
 
 async function invokeCommand<T>(command: string, args?: Record<string, unknown>): Promise<T> {
@@ -27,6 +45,20 @@ export async function saveSettings(settings: AppSettings): Promise<AppSettings>
   return invokeCommand<AppSettings>("save_settings", { settings });
 }
 
+/**
+ * Describes what the provider selected in `settings` supports and what it costs.
+ */
+export async function getProviderCapabilities(settings: AppSettings): Promise<ProviderCapabilities> {
+  return invokeCommand<ProviderCapabilities>("get_provider_capabilities", { settings });
+}
+
+/**
+ * Opens a native picker for a local whisper.cpp model file.
+ */
+export async function selectModelFile(): Promise<string | null> {
+  return invokeCommand<string | null>("select_model_file");
+}
+
 /**
  * Returns supported Whisper languages from the backend.
  */
@@ -41,6 +73,13 @@ export async function selectVideoFile(): Promise<string | null> {
   return invokeCommand<string | null>("select_video_file");
 }
 
+/**
+ * Opens a native picker that accepts several video files at once.
+ */
+export async function selectVideoFiles(): Promise<string[]> {
+  return invokeCommand<string[]>("select_video_files");
+}
+
 /**
  * Validates a video path and returns file metadata.
  */
@@ -56,10 +95,11 @@ export async function getVideoInfo(path: string): Promise<VideoInfo> {
 }
 
 /**
- * Calculates chunk boundaries based on duration and configured chunk length.
+ * Plans chunks the way processing will: chunking mode padding included, shortened to fit the
+ * provider's upload limit, with a notice when that happened.
  */
-export async function calculateChunks(durationSeconds: number, chunkDurationMinutes: number): Promise<ChunkInfo[]> {
-  return invokeCommand<ChunkInfo[]>("calculate_chunks", {
+export async function calculateChunks(durationSeconds: number, chunkDurationMinutes: number): Promise<ChunkPlan> {
+  return invokeCommand<ChunkPlan>("calculate_chunks", {
     durationSeconds,
     chunkDurationMinutes,
   });
@@ -78,3 +118,249 @@ export async function estimateCost(durationSeconds: number): Promise<CostEstimat
 export async function selectOutputFolder(): Promise<string | null> {
   return invokeCommand<string | null>("select_output_folder");
 }
+
+/**
+ * Runs the full transcription pipeline and writes the subtitle file.
+ */
+export async function processVideo(config: ProcessingConfig): Promise<ProcessingResult> {
+  return invokeCommand<ProcessingResult>("process_video", { config });
+}
+
+/**
+ * Converts a text subtitle track already embedded in the video to SRT, ASS or VTT, skipping
+ * transcription. Resolves to the written file path.
+ */
+export async function extractEmbeddedSubtitles(
+  videoPath: string,
+  streamIndex: number,
+  format: SubtitleFormat,
+  outputFolder: string | null,
+): Promise<string> {
+  return invokeCommand<string>("extract_embedded_subtitles", {
+    videoPath,
+    streamIndex,
+    format,
+    outputFolder,
+  });
+}
+
+/**
+ * Opens a native picker for existing subtitle files, guessing each file's language from
+ * `<name>.<language>.srt`.
+ */
+export async function selectSubtitleFiles(): Promise<MuxTrack[]> {
+  return invokeCommand<MuxTrack[]>("select_subtitle_files");
+}
+
+/**
+ * Writes `<name>.subtitled.<mkv|mp4>` with the given subtitle files added as soft tracks.
+ * The container follows the source video when not given. Resolves to the written path.
+ */
+export async function muxSubtitles(
+  videoPath: string,
+  tracks: MuxTrack[],
+  container: MuxContainer | null,
+  outputFolder: string | null,
+): Promise<string> {
+  return invokeCommand<string>("mux_subtitles", {
+    videoPath,
+    tracks,
+    container,
+    outputFolder,
+  });
+}
+
+/**
+ * Writes `<name>.burned.mp4` with the subtitle file drawn onto the picture. The video is
+ * re-encoded, so this takes about as long as a normal encode. Resolves to the written path.
+ */
+export async function burnInSubtitles(
+  videoPath: string,
+  subtitlePath: string,
+  style: SubtitleStyle,
+  outputFolder: string | null,
+): Promise<string> {
+  return invokeCommand<string>("burn_in_subtitles", {
+    videoPath,
+    subtitlePath,
+    style,
+    outputFolder,
+  });
+}
+
+/** Renders one frame at `timestampSeconds` with the subtitles burned in, as JPEG bytes. */
+export async function renderBurnInPreview(
+  videoPath: string,
+  subtitlePath: string,
+  style: SubtitleStyle,
+  timestampSeconds: number,
+): Promise<Uint8Array> {
+  const bytes = await invokeCommand<number[]>("render_burn_in_preview", {
+    videoPath,
+    subtitlePath,
+    style,
+    timestampSeconds,
+  });
+  return new Uint8Array(bytes);
+}
+
+/**
+ * Cancels the running pipeline. Resolves to false when nothing was running.
+ */
+export async function cancelProcessing(): Promise<boolean> {
+  return invokeCommand<boolean>("cancel_processing");
+}
+
+/**
+ * Resumes an unfinished job, skipping chunks that were already transcribed.
+ */
+export async function resumeJob(jobId: string): Promise<ProcessingResult> {
+  return invokeCommand<ProcessingResult>("resume_job", { jobId });
+}
+
+/**
+ * Lists jobs that were interrupted before their subtitle file was written.
+ */
+export async function listUnfinishedJobs(): Promise<JobSummary[]> {
+  return invokeCommand<JobSummary[]>("list_unfinished_jobs");
+}
+
+/**
+ * Deletes the saved progress of an unfinished job.
+ */
+export async function discardJob(jobId: string): Promise<void> {
+  return invokeCommand<void>("discard_job", { jobId });
+}
+
+/**
+ * Subscribes to pipeline progress events emitted by `process_video`.
+ */
+export async function onProcessingProgress(
+  handler: (progress: ProcessingProgress) => void,
+): Promise<UnlistenFn> {
+  return listen<ProcessingProgress>(PROCESSING_PROGRESS_EVENT, (event) => handler(event.payload));
+}
+
+/**
+ * Subscribes to retry notices emitted when a chunk's transcription request is retried.
+ */
+export async function onProcessingRetry(handler: (retry: ProcessingRetry) => void): Promise<UnlistenFn> {
+  return listen<ProcessingRetry>(PROCESSING_RETRY_EVENT, (event) => handler(event.payload));
+}
+
+/**
+ * Loads the persisted job queue.
+ */
+export async function getQueue(): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("get_queue");
+}
+
+/**
+ * Queues one job per video, each carrying the same overrides on top of the settings defaults.
+ */
+export async function addToQueue(videoPaths: string[], overrides: JobOverrides): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("add_to_queue", { videoPaths, overrides });
+}
+
+/**
+ * Removes a job from the queue, cancelling it and discarding its progress if it is running.
+ */
+export async function removeFromQueue(jobId: string): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("remove_from_queue", { jobId });
+}
+
+/**
+ * Moves the listed jobs to the front of the queue in the given order.
+ */
+export async function reorderQueue(jobIds: string[]): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("reorder_queue", { jobIds });
+}
+
+/**
+ * Pauses or resumes the whole queue. Pausing interrupts running jobs, which resume later.
+ */
+export async function setQueuePaused(paused: boolean): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("set_queue_paused", { paused });
+}
+
+/**
+ * Holds a single queued or running job until it is resumed.
+ */
+export async function pauseQueueJob(jobId: string): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("pause_queue_job", { jobId });
+}
+
+/**
+ * Returns a paused or failed job to the queue.
+ */
+export async function resumeQueueJob(jobId: string): Promise<JobQueue> {
+  return invokeCommand<JobQueue>("resume_queue_job", { jobId });
+}
+
+/**
+ * Subscribes to queue changes (jobs added, started, finished, reordered).
+ */
+export async function onQueueUpdated(handler: (queue: JobQueue) => void): Promise<UnlistenFn> {
+  return listen<JobQueue>(QUEUE_UPDATED_EVENT, (event) => handler(event.payload));
+}
+
+/**
+ * Subscribes to progress events of queued jobs.
+ */
+export async function onQueueProgress(handler: (progress: QueueJobProgress) => void): Promise<UnlistenFn> {
+  return listen<QueueJobProgress>(QUEUE_PROGRESS_EVENT, (event) => handler(event.payload));
+}
+
+/**
+ * Subscribes to retry notices of queued jobs.
+ */
+export async function onQueueRetry(handler: (retry: QueueJobRetry) => void): Promise<UnlistenFn> {
+  return listen<QueueJobRetry>(QUEUE_RETRY_EVENT, (event) => handler(event.payload));
+}
+
+/**
+ * Opens a native picker for a folder to watch for new recordings.
+ */
+export async function selectWatchFolder(): Promise<string | null> {
+  return invokeCommand<string | null>("select_watch_folder");
+}
+
+/**
+ * Lists the registered watch folders.
+ */
+export async function listWatchFolders(): Promise<WatchFolder[]> {
+  return invokeCommand<WatchFolder[]>("list_watch_folders");
+}
+
+/**
+ * Starts watching a folder. Files already in it are skipped unless `includeExisting` is set.
+ */
+export async function addWatchFolder(
+  path: string,
+  overrides: JobOverrides,
+  archiveFolder: string | null,
+  includeExisting: boolean,
+): Promise<WatchFolder[]> {
+  return invokeCommand<WatchFolder[]>("add_watch_folder", { path, overrides, archiveFolder, includeExisting });
+}
+
+/**
+ * Saves a watch folder's enabled flag, overrides and archive folder.
+ */
+export async function updateWatchFolder(folder: WatchFolder): Promise<WatchFolder[]> {
+  return invokeCommand<WatchFolder[]>("update_watch_folder", { folder });
+}
+
+/**
+ * Stops watching a folder. Jobs it already queued are kept.
+ */
+export async function removeWatchFolder(folderId: string): Promise<WatchFolder[]> {
+  return invokeCommand<WatchFolder[]>("remove_watch_folder", { folderId });
+}
+
+/**
+ * Subscribes to watch folder changes.
+ */
+export async function onWatchFoldersUpdated(handler: (folders: WatchFolder[]) => void): Promise<UnlistenFn> {
+  return listen<WatchFolder[]>(WATCH_FOLDERS_UPDATED_EVENT, (event) => handler(event.payload));
+}
@@ -1,3 +1,40 @@
+import type { SubtitleFormat } from "./settings";
+
+export interface AudioStreamInfo {
+  index: number;
+  codecName: string;
+  channels: number | null;
+  channelLayout: string | null;
+  sampleRate: number | null;
+  bitRate: number | null;
+  language: string | null;
+  title: string | null;
+  isDefault: boolean;
+}
+
+export interface SubtitleStreamInfo {
+  index: number;
+  codecName: string;
+  language: string | null;
+  title: string | null;
+  isDefault: boolean;
+  isForced: boolean;
+  isText: boolean;
+}
+
+export type MuxContainer = "mkv" | "mp4";
+
+export interface MuxTrack {
+  path: string;
+  language: string | null;
+}
+
+export interface ChapterInfo {
+  startSeconds: number;
+  endSeconds: number;
+  title: string | null;
+}
+
 export interface VideoInfo {
   path: string;
   fileName: string;
@@ -7,6 +44,15 @@ export interface VideoInfo {
   codecName: string;
   width: number | null;
   height: number | null;
+  bitRate: number | null;
+  startTimeSeconds: number;
+  videoBitRate: number | null;
+  frameRate: number | null;
+  variableFrameRate: boolean;
+  rotation: number;
+  audioStreams: AudioStreamInfo[];
+  subtitleStreams: SubtitleStreamInfo[];
+  chapters: ChapterInfo[];
 }
 
 export interface ChunkInfo {
@@ -14,6 +60,15 @@ export interface ChunkInfo {
   startTimeSeconds: number;
   endTimeSeconds: number;
   durationSeconds: number;
+  paddedStartSeconds: number;
+  paddedEndSeconds: number;
+  expectedSizeBytes: number;
+}
+
+export interface ChunkPlan {
+  chunks: ChunkInfo[];
+  chunkDurationMinutes: number;
+  notice: string | null;
 }
 
 export interface CostEstimate {
@@ -24,3 +79,63 @@ export interface CostEstimate {
   warningThresholdUsd: number;
   exceedsWarningThreshold: boolean;
 }
+
+export interface ProcessingConfig {
+  videoPath: string;
+  sourceLanguage: string;
+  targetLanguage: string | null;
+  outputFolder: string | null;
+  outputFormat?: SubtitleFormat | null;
+  audioStreamIndex?: number | null;
+}
+
+export type ProcessingStage =
+  | "analyzing"
+  | "extracting"
+  | "transcribing"
+  | "generating"
+  | "translating"
+  | "muxing"
+  | "rendering"
+  | "complete"
+  | "cancelled";
+
+export interface ProcessingProgress {
+  stage: ProcessingStage;
+  chunkNumber: number;
+  totalChunks: number;
+  percentage: number;
+  message: string;
+}
+
+export interface ProcessingRetry {
+  chunkNumber: number;
+  totalChunks: number;
+  retry: number;
+  maxRetries: number;
+  delaySeconds: number;
+  error: string;
+}
+
+export interface ProcessingResult {
+  outputPath: string;
+  outputLanguage: string;
+  translatedOutputPath: string | null;
+  segmentCount: number;
+  chunkCount: number;
+}
+
+export interface JobSummary {
+  id: string;
+  inputPath: string;
+  outputPath: string;
+  createdAt: number;
+  updatedAt: number;
+  totalChunks: number;
+  completedChunks: number;
+  failedChunks: number;
+  lastError: string | null;
+}
+
+export const PROCESSING_PROGRESS_EVENT = "processing-progress";
+export const PROCESSING_RETRY_EVENT = "processing-retry";
@@ -0,0 +1,36 @@
+import type { ProcessingProgress, ProcessingRetry } from "./processing";
+import type { SubtitleFormat } from "./settings";
+
+export interface JobOverrides {
+  sourceLanguage: string | null;
+  targetLanguage: string | null;
+  outputFolder: string | null;
+  outputFormat: SubtitleFormat | null;
+  audioStreamIndex: number | null;
+}
+
+export type QueueJobStatus = "queued" | "running" | "paused" | "completed" | "failed";
+
+export interface QueueJob {
+  id: string;
+  videoPath: string;
+  overrides: JobOverrides;
+  status: QueueJobStatus;
+  addedAt: number;
+  outputPath: string | null;
+  error: string | null;
+  watchFolderId: string | null;
+}
+
+export interface JobQueue {
+  paused: boolean;
+  jobs: QueueJob[];
+}
+
+export type QueueJobProgress = ProcessingProgress & { jobId: string };
+
+export type QueueJobRetry = ProcessingRetry & { jobId: string };
+
+export const QUEUE_UPDATED_EVENT = "queue-updated";
+export const QUEUE_PROGRESS_EVENT = "queue-progress";
+export const QUEUE_RETRY_EVENT = "queue-retry";
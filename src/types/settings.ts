@@ -1,4 +1,13 @@
 import { WHISPER_LANGUAGES, type LanguageCode } from "./languages";
+import type { StylePreset } from "./style";
+
+export type ProviderKind = "openai" | "azure" | "compatible" | "whispercpp";
+
+export type ChunkingMode = "fixed" | "silence" | "overlap";
+
+export type TranslationMode = "chat" | "audio";
+
+export type SubtitleFormat = "srt" | "ass" | "vtt";
 
 export interface AppSettings {
   apiKey: string;
@@ -8,6 +17,26 @@ export interface AppSettings {
   maxRetries: number;
   sourceLanguage: LanguageCode;
   targetLanguage: LanguageCode | null;
+  provider: ProviderKind;
+  apiBaseUrl: string;
+  azureEndpoint: string;
+  azureDeployment: string;
+  azureApiVersion: string;
+  compatibleModel: string;
+  compatiblePricePerMinuteUsd: number;
+  whisperCppModelPath: string | null;
+  translationApiBaseUrl: string;
+  translationModel: string;
+  translationApiKey: string;
+  translationMode: TranslationMode;
+  chunkingMode: ChunkingMode;
+  silenceWindowSeconds: number;
+  chunkOverlapSeconds: number;
+  defaultOutputFormat: SubtitleFormat;
+  maxConcurrentJobs: number;
+  stylePresets: StylePreset[];
+  /** Preset used for ASS output; the built-in default style when `null`. */
+  activeStylePreset: string | null;
 }
 
 export const DEFAULT_SETTINGS: AppSettings = {
@@ -18,4 +47,33 @@ export const DEFAULT_SETTINGS: AppSettings = {
   maxRetries: 3,
   sourceLanguage: "en",
   targetLanguage: null,
+  provider: "openai",
+  apiBaseUrl: "https://api.openai.com",
+  azureEndpoint: "",
+  azureDeployment: "",
+  azureApiVersion: "2024-06-01",
+  compatibleModel: "whisper-1",
+  compatiblePricePerMinuteUsd: 0,
+  whisperCppModelPath: null,
+  translationApiBaseUrl: "https://api.openai.com",
+  translationModel: "gpt-4o-mini",
+  translationApiKey: "",
+  translationMode: "chat",
+  chunkingMode: "fixed",
+  silenceWindowSeconds: 30,
+  chunkOverlapSeconds: 10,
+  defaultOutputFormat: "srt",
+  maxConcurrentJobs: 1,
+  stylePresets: [],
+  activeStylePreset: null,
 };
+
+export interface ProviderCapabilities {
+  maxUploadBytes: number;
+  audioFormat: string;
+  responseFormats: string[];
+  wordTimestamps: boolean;
+  translation: boolean;
+  requiresApiKey: boolean;
+  pricePerMinuteUsd: number;
+}
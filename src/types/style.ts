@@ -0,0 +1,54 @@
+export type SubtitlePosition = "top" | "middle" | "bottom";
+
+export type HorizontalAlignment = "left" | "center" | "right";
+
+export interface SubtitleStyle {
+  fontName: string;
+  fontSize: number;
+  bold: boolean;
+  italic: boolean;
+  /** `#RRGGBB`. */
+  primaryColor: string;
+  /** `#RRGGBB`, the colour karaoke words change to once spoken. */
+  highlightColor: string;
+  /** `#RRGGBB`. */
+  outlineColor: string;
+  outlineWidth: number;
+  position: SubtitlePosition;
+  alignment: HorizontalAlignment;
+  marginVertical: number;
+  marginHorizontal: number;
+}
+
+export interface LanguageStyle {
+  language: string;
+  style: SubtitleStyle;
+}
+
+/** Applies to cues that start with `<speaker>:`; the label is moved into the ASS name field. */
+export interface SpeakerStyle {
+  speaker: string;
+  style: SubtitleStyle;
+}
+
+export interface StylePreset {
+  name: string;
+  style: SubtitleStyle;
+  languageStyles: LanguageStyle[];
+  speakerStyles: SpeakerStyle[];
+}
+
+export const DEFAULT_SUBTITLE_STYLE: SubtitleStyle = {
+  fontName: "Arial",
+  fontSize: 22,
+  bold: false,
+  italic: false,
+  primaryColor: "#FFFFFF",
+  highlightColor: "#FFFF00",
+  outlineColor: "#000000",
+  outlineWidth: 2,
+  position: "bottom",
+  alignment: "center",
+  marginVertical: 16,
+  marginHorizontal: 10,
+};
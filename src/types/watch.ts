@@ -0,0 +1,13 @@
+import type { JobOverrides } from "./queue";
+
+export interface WatchFolder {
+  id: string;
+  path: string;
+  enabled: boolean;
+  overrides: JobOverrides;
+  archiveFolder: string | null;
+  existingFiles: string[];
+  addedAt: number;
+}
+
+export const WATCH_FOLDERS_UPDATED_EVENT = "watch-folders-updated";